@@ -0,0 +1,22 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Typed models and an async client for the PowerDNS HTTP API.
+//!
+//! The models of servers, zones and resource record sets live in [`pdns`], the clients talking to
+//! the API in [`rest_client`]. All client operations fail with a [`RestClientError`], its
+//! [`RestClientErrorKind`] tells the cause apart.
+pub mod pdns;
+pub mod rest_client;
+
+pub use rest_client::errors::{RestClientError, RestClientErrorKind};
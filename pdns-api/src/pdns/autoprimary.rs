@@ -0,0 +1,70 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Primary server allowed to provision secondary zones by sending a NOTIFY for an unknown zone
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Autoprimary {
+    ip: String,
+    nameserver: String,
+    #[serde(default)]
+    account: String,
+}
+
+impl Autoprimary {
+    pub fn new(ip: &String, nameserver: &String, account: &Option<String>) -> Autoprimary {
+        Autoprimary {
+            ip: ip.clone(),
+            nameserver: nameserver.clone(),
+            account: account.clone().unwrap_or(String::new()),
+        }
+    }
+
+    pub fn ip(&self) -> String {
+        self.ip.clone()
+    }
+
+    pub fn nameserver(&self) -> String {
+        self.nameserver.clone()
+    }
+
+    pub fn account(&self) -> String {
+        self.account.clone()
+    }
+}
+
+impl Display for Autoprimary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(ip: {}, nameserver: {}, account: {})", &self.ip, &self.nameserver, &self.account)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pdns::autoprimary::Autoprimary;
+
+    #[test]
+    fn should_deserialize_autoprimary_without_account() {
+        let src = r#"[{"ip": "192.0.2.1", "nameserver": "ns1.example.org"}]"#;
+
+        let autoprimaries: Vec<Autoprimary> = serde_json::from_str(src).unwrap();
+
+        assert_eq!(autoprimaries, vec![Autoprimary::new(&"192.0.2.1".to_string(),
+                                                        &"ns1.example.org".to_string(), &None)]);
+        assert_eq!(serde_json::to_string(&autoprimaries[0]).unwrap(),
+                   r#"{"ip":"192.0.2.1","nameserver":"ns1.example.org","account":""}"#);
+    }
+}
@@ -0,0 +1,322 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::path::{Component, Path};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::pdns::cryptokey::{Cryptokey, NewCryptokey};
+use crate::pdns::metadata::Metadata;
+use crate::pdns::version::Capability;
+use crate::pdns::zone::{NewZone, Zone, ZoneUpdate};
+
+/// Layout version of the backup archive, raised on incompatible changes
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// Name of the archive index within the backup directory
+pub const BACKUP_MANIFEST_FILE: &'static str = "manifest.json";
+
+/// Index of a backup archive, written after all zone files
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BackupManifest {
+    format_version: u32,
+    created_at: String,
+    server: String,
+    server_version: String,
+    zones: Vec<BackupEntry>,
+}
+
+/// Zone of the archive and the file holding its backup
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BackupEntry {
+    name: String,
+    file: String,
+}
+
+/// State of a single zone at backup time
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ZoneBackup {
+    zone: Zone,
+    metadata: Vec<Metadata>,
+    cryptokeys: Vec<Cryptokey>,
+}
+
+impl BackupManifest {
+    pub fn new(created_at: &DateTime<Utc>, server: &String, server_version: &String,
+               zones: &Vec<BackupEntry>) -> BackupManifest {
+        BackupManifest {
+            format_version: BACKUP_FORMAT_VERSION,
+            created_at: created_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+            server: server.clone(),
+            server_version: server_version.clone(),
+            zones: zones.clone(),
+        }
+    }
+
+    pub fn format_version(&self) -> u32 {
+        self.format_version
+    }
+
+    pub fn created_at(&self) -> String {
+        self.created_at.clone()
+    }
+
+    pub fn server(&self) -> String {
+        self.server.clone()
+    }
+
+    pub fn server_version(&self) -> String {
+        self.server_version.clone()
+    }
+
+    pub fn zones(&self) -> Vec<BackupEntry> {
+        self.zones.clone()
+    }
+}
+
+impl BackupEntry {
+    /// The file name is the zone name without its final dot, e.g. 'zones/example.org.json'. All bytes
+    /// but lower case letters, digits, '-', '_' and '.' are percent encoded, so names differing only in
+    /// case don't collide on case insensitive file systems. The root zone is stored as 'zones/%2E.json'.
+    pub fn new(zone_name: &String) -> BackupEntry {
+        let name = zone_name.strip_suffix('.').unwrap_or(zone_name);
+
+        BackupEntry {
+            name: zone_name.clone(),
+            file: format!("zones/{}.json", match name {
+                "" => "%2E".to_string(),
+                name => encode_file_name(name),
+            }),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Path relative to the backup directory
+    pub fn file(&self) -> String {
+        self.file.clone()
+    }
+
+    /// Whether the file stays within the backup directory, the manifest of an archive is not trusted
+    pub fn is_safe_file(&self) -> bool {
+        let path = Path::new(&self.file);
+
+        path.components().count() > 0 && path.components().all(|component| matches!(component, Component::Normal(_)))
+    }
+}
+
+impl ZoneBackup {
+    pub fn new(zone: &Zone, metadata: &Vec<Metadata>, cryptokeys: &Vec<Cryptokey>) -> ZoneBackup {
+        ZoneBackup {
+            zone: zone.clone(),
+            metadata: metadata.clone(),
+            cryptokeys: cryptokeys.clone(),
+        }
+    }
+
+    pub fn zone(&self) -> Zone {
+        self.zone.clone()
+    }
+
+    pub fn metadata(&self) -> Vec<Metadata> {
+        self.metadata.clone()
+    }
+
+    pub fn cryptokeys(&self) -> Vec<Cryptokey> {
+        self.cryptokeys.clone()
+    }
+
+    /// Zone creation without DNSSEC state. Keys are imported afterwards, as creating a secured zone
+    /// generates new keys, and SOA-EDIT-API is suspended to keep the serial of the backup.
+    pub fn new_zone(&self) -> NewZone {
+        NewZone::new(&self.zone.name(), self.zone.kind(), &self.zone.rrsets(), &self.zone.masters(), &[])
+            .with_presigned(self.zone.presigned())
+            .with_soa_edit(Some(self.zone.soa_edit()).filter(|soa_edit| !soa_edit.is_empty()), Some(String::new()))
+            .with_api_rectify(self.zone.api_rectify())
+            .with_account(self.zone.account())
+            .with_catalog(self.zone.catalog())
+            .with_tsig_key_ids(Some(self.zone.master_tsig_key_ids()), Some(self.zone.slave_tsig_key_ids()))
+    }
+
+    /// Settings applied after the key import
+    pub fn zone_update(&self) -> ZoneUpdate {
+        ZoneUpdate::new()
+            .with_soa_edit(None, Some(self.zone.soa_edit_api()))
+            .with_dnssec(None, Some(self.zone.nsec3param()).filter(|nsec3param| !nsec3param.is_empty()))
+    }
+
+    /// Metadata which is not restored through the zone itself
+    pub fn restorable_metadata(&self) -> Vec<Metadata> {
+        self.metadata.iter()
+            .filter(|metadata| !metadata.is_protected())
+            .cloned()
+            .collect()
+    }
+
+    pub fn importable_cryptokeys(&self) -> Vec<NewCryptokey> {
+        self.cryptokeys.iter()
+            .filter_map(|cryptokey| cryptokey.new_cryptokey())
+            .collect()
+    }
+
+    pub fn required_capabilities(&self) -> Vec<Capability> {
+        let mut capabilities: Vec<Capability> = Vec::new();
+
+        if let Some(capability) = Capability::for_zone_kind(&self.zone.kind().to_string().to_lowercase()) {
+            capabilities.push(capability);
+        }
+        if self.zone.catalog().is_some() {
+            capabilities.push(Capability::CatalogZones);
+        }
+        for rrset in self.zone.rrsets().iter() {
+            match Capability::for_record_type(&rrset.type_id().to_string()) {
+                Some(capability) if !capabilities.contains(&capability) => capabilities.push(capability),
+                _ => {}
+            }
+        }
+        for metadata in self.restorable_metadata().iter() {
+            match Capability::for_metadata_kind(&metadata.kind()) {
+                Some(capability) if !capabilities.contains(&capability) => capabilities.push(capability),
+                _ => {}
+            }
+        }
+
+        capabilities
+    }
+}
+
+fn encode_file_name(name: &str) -> String {
+    name.bytes()
+        .map(|byte| match byte {
+            b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Directory of a backup, e.g. pdns-backup-20211101T120000Z
+pub fn backup_directory_name(created_at: &DateTime<Utc>) -> String {
+    format!("pdns-backup-{}", created_at.format("%Y%m%dT%H%M%SZ"))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::pdns::backup::{backup_directory_name, BackupEntry, BackupManifest, ZoneBackup};
+    use crate::pdns::version::Capability;
+
+    const ZONE_BACKUP: &'static str = r#"
+    {
+        "zone": {
+            "id": "example.org.", "name": "example.org.", "type": "Zone", "url": "/", "kind": "Producer",
+            "rrsets": [
+                {"name": "example.org.", "type": "SOA", "ttl": 3600, "changetype": null, "comments": [],
+                 "records": [{"content": "example.org. root.example.org. 2021110101 10800 3600 604800 3600", "disabled": false}]},
+                {"name": "lua.example.org.", "type": "LUA", "ttl": 60, "changetype": null, "comments": [],
+                 "records": [{"content": "A \"ifportup(443, {'192.0.2.1'})\"", "disabled": false}]}
+            ],
+            "serial": 2021110101, "edited_serial": 2021110101, "masters": [], "dnssec": true,
+            "nsec3param": "1 0 0 -", "nsec3narrow": false, "soa_edit": "", "soa_edit_api": "INCEPTION-INCREMENT",
+            "api_rectify": true, "account": "", "catalog": "", "master_tsig_key_ids": [], "slave_tsig_key_ids": []
+        },
+        "metadata": [
+            {"kind": "ALSO-NOTIFY", "metadata": ["192.0.2.53"]},
+            {"kind": "IXFR", "metadata": ["1"]},
+            {"kind": "NSEC3PARAM", "metadata": ["1 0 0 -"]}
+        ],
+        "cryptokeys": [
+            {"id": 1, "keytype": "csk", "active": true, "published": true, "dnskey": "257 3 13 AAAA",
+             "privatekey": "Private-key-format: v1.2\n"}
+        ]
+    }
+    "#;
+
+    #[test]
+    fn should_create_zone_without_dnssec_state() {
+        let backup: ZoneBackup = serde_json::from_str(ZONE_BACKUP).unwrap();
+        let new_zone = serde_json::to_value(backup.new_zone()).unwrap();
+        let zone_update = serde_json::to_value(backup.zone_update()).unwrap();
+
+        assert_eq!(new_zone["dnssec"], false);
+        assert_eq!(new_zone["nsec3param"], serde_json::Value::Null);
+        assert_eq!(new_zone["soa_edit_api"], "");
+        assert_eq!(new_zone["rrsets"].as_array().unwrap().len(), 2);
+        assert_eq!(zone_update, serde_json::json!({"soa_edit_api": "INCEPTION-INCREMENT", "nsec3param": "1 0 0 -"}));
+        assert_eq!(backup.restorable_metadata().len(), 2);
+        assert_eq!(backup.importable_cryptokeys().len(), 1);
+        assert_eq!(backup.required_capabilities(),
+                   vec![Capability::ProducerConsumerKinds, Capability::LuaRecords, Capability::Ixfr]);
+    }
+
+    #[test]
+    fn should_keep_unlisted_record_types() {
+        let mut backup: serde_json::Value = serde_json::from_str(ZONE_BACKUP).unwrap();
+
+        backup["zone"]["presigned"] = serde_json::json!(true);
+        backup["zone"]["rrsets"].as_array_mut().unwrap().extend(vec![
+            serde_json::json!({"name": "example.org.", "type": "LOC", "ttl": 3600, "changetype": null, "comments": [],
+                               "records": [{"content": "52 22 23.000 N 4 53 32.000 E -2.00m 0.00m 10000m 10m", "disabled": false}]}),
+            serde_json::json!({"name": "example.org.", "type": "DNSKEY", "ttl": 3600, "changetype": null, "comments": [],
+                               "records": [{"content": "257 3 13 AAAA", "disabled": false}]}),
+        ]);
+
+        let backup: ZoneBackup = serde_json::from_value(backup).unwrap();
+        let new_zone = serde_json::to_value(backup.new_zone()).unwrap();
+        let types: Vec<&str> = new_zone["rrsets"].as_array().unwrap()
+            .iter()
+            .map(|rrset| rrset["type"].as_str().unwrap())
+            .collect();
+
+        assert_eq!(types, vec!["SOA", "LUA", "LOC", "DNSKEY"]);
+        assert_eq!(new_zone["presigned"], true);
+    }
+
+    #[test]
+    fn should_name_backup_files() {
+        let created_at = Utc.ymd(2021, 11, 1).and_hms(12, 0, 0);
+        let manifest = BackupManifest::new(&created_at, &"http://localhost:8081".to_string(), &"4.5.2".to_string(),
+                                           &vec![BackupEntry::new(&"example.org.".to_string()),
+                                                 BackupEntry::new(&".".to_string())]);
+
+        assert_eq!(backup_directory_name(&created_at), "pdns-backup-20211101T120000Z");
+        assert_eq!(manifest.created_at(), "2021-11-01T12:00:00Z");
+        assert_eq!(manifest.zones()[0].file(), "zones/example.org.json");
+        assert_eq!(manifest.zones()[1].file(), "zones/%2E.json");
+    }
+
+    #[test]
+    fn should_keep_backup_file_names_apart() {
+        let file = |name: &str| BackupEntry::new(&name.to_string()).file();
+
+        assert_ne!(file("."), file("root."));
+        assert_eq!(file("root."), "zones/root.json");
+        assert_ne!(file("Example.org."), file("example.org."));
+        assert_eq!(file("a/b.example.org."), "zones/a%2Fb.example.org.json");
+        assert_eq!(file("100%.example.org."), "zones/100%25.example.org.json")
+    }
+
+    #[test]
+    fn should_reject_unsafe_backup_files() {
+        let entry = |file: &str| serde_json::from_value::<BackupEntry>(serde_json::json!({"name": "example.org.", "file": file})).unwrap();
+
+        assert!(BackupEntry::new(&"example.org.".to_string()).is_safe_file());
+        assert!(!entry("../../etc/passwd").is_safe_file());
+        assert!(!entry("zones/../../secret.json").is_safe_file());
+        assert!(!entry("/etc/passwd").is_safe_file());
+        assert!(!entry("").is_safe_file())
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Outcome of a cache flush, count is the number of removed cache entries
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CacheFlushResult {
+    count: u64,
+    result: String,
+}
+
+impl CacheFlushResult {
+    pub fn new(count: u64, result: &String) -> CacheFlushResult {
+        CacheFlushResult {
+            count,
+            result: result.clone(),
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn result(&self) -> String {
+        self.result.clone()
+    }
+}
+
+impl Display for CacheFlushResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "count={}, result={}", self.count, &self.result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pdns::cache::CacheFlushResult;
+
+    #[test]
+    fn should_deserialize_cache_flush_result() {
+        let src = r#"{"count": 3, "result": "Flushed cache."}"#;
+        let result = serde_json::from_str::<CacheFlushResult>(src).unwrap();
+
+        assert_eq!(result, CacheFlushResult::new(3, &"Flushed cache.".to_string()))
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+use crate::pdns::dns_name::DnsName;
+use crate::pdns::zone::{ListZone, RrsetType, Zone, ZoneKind};
+
+/// Label below the catalog zone apex holding the member entries, see RFC 9432
+const MEMBER_ZONES_LABEL: &'static str = "zones";
+
+/// Disagreement between a catalog zone, its member entries and the zones naming it as their catalog
+#[derive(Clone, Debug, PartialEq)]
+pub enum CatalogIssue {
+    NotACatalog { kind: ZoneKind },
+    MemberKindMismatch { member: String, kind: ZoneKind },
+    MissingFromCatalog { member: String },
+    UnknownMember { member: String },
+}
+
+/// Zones naming the catalog as their catalog, ordered by name
+pub fn catalog_members(catalog: &String, zones: &Vec<ListZone>) -> Vec<ListZone> {
+    let catalog = DnsName::absolute(catalog);
+    let mut members: Vec<ListZone> = zones.iter()
+        .filter(|zone| zone.catalog().map(|name| DnsName::absolute(&name)) == Some(catalog.clone()))
+        .cloned()
+        .collect();
+
+    members.sort_by_key(|zone| zone.name());
+
+    members
+}
+
+/// Member zones listed by PTR records '<unique id>.zones.<catalog>' in the catalog zone content
+pub fn member_entries(catalog_zone: &Zone) -> BTreeSet<String> {
+    let member_suffix = format!(".{}.{}", MEMBER_ZONES_LABEL, DnsName::absolute(&catalog_zone.name()));
+
+    catalog_zone.rrsets()
+        .iter()
+        .filter(|rrset| rrset.type_id() == RrsetType::Ptr)
+        .filter(|rrset| DnsName::absolute(&rrset.name()).to_string()
+            .strip_suffix(member_suffix.as_str())
+            .map(|unique_id| !unique_id.is_empty() && !unique_id.contains('.'))
+            .unwrap_or(false))
+        .flat_map(|rrset| rrset.records())
+        .map(|record| DnsName::absolute(&record.content()).to_string())
+        .collect()
+}
+
+/// Compare the catalog zone with the zones naming it as their catalog. Producers of servers
+/// generating the member entries on transfer hold no entries, their member list is not compared.
+pub fn check_catalog(catalog_zone: &Zone, members: &Vec<ListZone>) -> Vec<CatalogIssue> {
+    let kind = catalog_zone.kind();
+
+    if !kind.is_catalog() {
+        return vec![CatalogIssue::NotACatalog { kind }];
+    }
+
+    let mut issues: Vec<CatalogIssue> = members.iter()
+        .filter(|member| member.kind().is_secondary() != (kind == ZoneKind::Consumer))
+        .map(|member| CatalogIssue::MemberKindMismatch { member: member.name(), kind: member.kind() })
+        .collect();
+    let entries = member_entries(catalog_zone);
+
+    if entries.is_empty() && kind == ZoneKind::Producer {
+        return issues;
+    }
+
+    let member_names: BTreeSet<String> = members.iter()
+        .map(|member| DnsName::absolute(&member.name()).to_string())
+        .collect();
+
+    issues.extend(member_names.difference(&entries)
+        .map(|member| CatalogIssue::MissingFromCatalog { member: member.clone() }));
+    issues.extend(entries.difference(&member_names)
+        .map(|member| CatalogIssue::UnknownMember { member: member.clone() }));
+
+    issues
+}
+
+impl CatalogIssue {
+    /// Zone the issue is about, None for the catalog zone itself
+    pub fn zone(&self) -> Option<String> {
+        match self {
+            CatalogIssue::NotACatalog { .. } => None,
+            CatalogIssue::MemberKindMismatch { member, .. } => Some(member.clone()),
+            CatalogIssue::MissingFromCatalog { member } => Some(member.clone()),
+            CatalogIssue::UnknownMember { member } => Some(member.clone()),
+        }
+    }
+}
+
+impl Display for CatalogIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CatalogIssue::NotACatalog { kind } => write!(f, "zone of kind {} is no catalog", kind),
+            CatalogIssue::MemberKindMismatch { member: _, kind } => write!(f, "member zone of kind {} does not match the catalog", kind),
+            CatalogIssue::MissingFromCatalog { member: _ } => write!(f, "member zone missing from the catalog zone"),
+            CatalogIssue::UnknownMember { member: _ } => write!(f, "catalog zone entry without member zone"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pdns::catalog::{catalog_members, check_catalog, CatalogIssue};
+    use crate::pdns::zone::{ListZone, Zone, ZoneKind};
+
+    fn catalog_zone(kind: &str, members: &[(&str, &str)]) -> Zone {
+        let rrsets: Vec<String> = members.iter()
+            .map(|(unique_id, member)| format!(
+                r#"{{"name": "{}.zones.cat.example.", "type": "PTR", "ttl": 0, "changetype": null,
+                    "records": [{{"content": "{}", "disabled": false}}], "comments": []}}"#, unique_id, member))
+            .collect();
+
+        serde_json::from_str(format!(r#"{{"id": "cat.example.", "name": "cat.example.", "type": "Zone",
+            "url": "/api/v1/servers/localhost/zones/cat.example.", "kind": "{}", "rrsets": [{}],
+            "serial": 1, "edited_serial": 1, "masters": [], "dnssec": false, "nsec3param": "",
+            "nsec3narrow": false, "soa_edit": "", "soa_edit_api": "", "api_rectify": false,
+            "master_tsig_key_ids": [], "slave_tsig_key_ids": []}}"#, kind, rrsets.join(", ")).as_str()).unwrap()
+    }
+
+    fn list_zone(name: &str, kind: &str, catalog: &str) -> ListZone {
+        serde_json::from_str(format!(r#"{{"id": "{0}", "name": "{0}", "url": "/api/v1/servers/localhost/zones/{0}",
+            "kind": "{1}", "serial": 1, "edited_serial": 1, "notified_serial": 1, "last_check": 0,
+            "masters": [], "dnssec": false, "catalog": "{2}"}}"#, name, kind, catalog).as_str()).unwrap()
+    }
+
+    #[test]
+    fn should_select_members_of_catalog() {
+        let zones = vec![list_zone("b.example.", "Master", "cat.example."), list_zone("a.example.", "Master", "cat.example"),
+                         list_zone("c.example.", "Master", "")];
+        let members: Vec<String> = catalog_members(&"cat.example.".to_string(), &zones).iter()
+            .map(|zone| zone.name())
+            .collect();
+
+        assert_eq!(members, vec!["a.example.".to_string(), "b.example.".to_string()])
+    }
+
+    #[test]
+    fn should_report_missing_and_unknown_members() {
+        let catalog = catalog_zone("Consumer", &[("1a2b", "a.example."), ("3c4d", "gone.example.")]);
+        let members = vec![list_zone("a.example.", "Slave", "cat.example."),
+                           list_zone("b.example.", "Slave", "cat.example."),
+                           list_zone("c.example.", "Master", "cat.example.")];
+
+        assert_eq!(check_catalog(&catalog, &members), vec![
+            CatalogIssue::MemberKindMismatch { member: "c.example.".to_string(), kind: ZoneKind::Master },
+            CatalogIssue::MissingFromCatalog { member: "b.example.".to_string() },
+            CatalogIssue::MissingFromCatalog { member: "c.example.".to_string() },
+            CatalogIssue::UnknownMember { member: "gone.example.".to_string() },
+        ])
+    }
+
+    #[test]
+    fn should_accept_producer_with_generated_entries() {
+        let catalog = catalog_zone("Producer", &[]);
+        let members = vec![list_zone("a.example.", "Master", "cat.example.")];
+
+        assert_eq!(check_catalog(&catalog, &members), Vec::new())
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::pdns::struct_type::StructType;
+
+/// Setting of the running server. The recursor reports list settings like allow-from as arrays.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ConfigSetting {
+    name: String,
+    #[serde(rename = "type")]
+    type_id: StructType,
+    value: ConfigValue,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConfigValue {
+    Single(String),
+    List(Vec<String>),
+}
+
+impl ConfigSetting {
+    pub fn new(name: &String, value: &ConfigValue) -> ConfigSetting {
+        ConfigSetting {
+            name: name.clone(),
+            type_id: StructType::ConfigSetting,
+            value: value.clone(),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn type_id(&self) -> StructType {
+        self.type_id.clone()
+    }
+
+    pub fn value(&self) -> ConfigValue {
+        self.value.clone()
+    }
+}
+
+impl Display for ConfigSetting {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", &self.name, &self.value)
+    }
+}
+
+impl Display for ConfigValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigValue::Single(value) => write!(f, "{}", value),
+            ConfigValue::List(values) => write!(f, "{}", values.join(", ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pdns::config::{ConfigSetting, ConfigValue};
+
+    #[test]
+    fn should_deserialize_config_settings() {
+        let src = r#"
+        [
+            { "name": "allow-axfr-ips", "type": "ConfigSetting", "value": "127.0.0.0/8,::1" },
+            { "name": "allow-from", "type": "ConfigSetting", "value": [ "127.0.0.0/8", "::1/128" ] }
+        ]
+        "#;
+
+        let settings: Vec<ConfigSetting> = serde_json::from_str(src).unwrap();
+
+        assert_eq!(settings, vec![
+            ConfigSetting::new(&"allow-axfr-ips".to_string(), &ConfigValue::Single("127.0.0.0/8,::1".to_string())),
+            ConfigSetting::new(&"allow-from".to_string(),
+                               &ConfigValue::List(vec!["127.0.0.0/8".to_string(), "::1/128".to_string()])),
+        ]);
+        assert_eq!(settings[1].value().to_string(), "127.0.0.0/8, ::1/128".to_string())
+    }
+}
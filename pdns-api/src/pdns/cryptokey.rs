@@ -0,0 +1,119 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// DNSSEC key of a zone. The private key is only reported when a single key is requested.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Cryptokey {
+    id: u32,
+    keytype: String,
+    active: bool,
+    published: Option<bool>,
+    dnskey: String,
+    ds: Option<Vec<String>>,
+    cds: Option<Vec<String>>,
+    privatekey: Option<String>,
+    algorithm: Option<String>,
+    bits: Option<u32>,
+}
+
+/// Key import, the private key is given in the ISC format
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NewCryptokey {
+    keytype: String,
+    active: bool,
+    published: bool,
+    privatekey: String,
+}
+
+impl Cryptokey {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn keytype(&self) -> String {
+        self.keytype.clone()
+    }
+
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    pub fn published(&self) -> bool {
+        self.published.unwrap_or(true)
+    }
+
+    pub fn dnskey(&self) -> String {
+        self.dnskey.clone()
+    }
+
+    pub fn privatekey(&self) -> Option<String> {
+        self.privatekey.clone()
+    }
+
+    pub fn algorithm(&self) -> Option<String> {
+        self.algorithm.clone()
+    }
+
+    /// Import request recreating the key in the same state, None without private key
+    pub fn new_cryptokey(&self) -> Option<NewCryptokey> {
+        self.privatekey.as_ref().map(|privatekey| NewCryptokey {
+            keytype: self.keytype.clone(),
+            active: self.active,
+            published: self.published(),
+            privatekey: privatekey.clone(),
+        })
+    }
+}
+
+impl Display for Cryptokey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(id: {}, keytype: {}, active: {}, published: {}, algorithm: {})",
+               self.id,
+               &self.keytype,
+               self.active,
+               self.published(),
+               self.algorithm.clone().unwrap_or(String::new()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pdns::cryptokey::Cryptokey;
+
+    #[test]
+    fn should_import_key_only_with_private_key() {
+        let src = r#"
+        [
+            {"type": "Cryptokey", "id": 1, "keytype": "csk", "active": true, "published": false,
+             "dnskey": "257 3 13 AAAA", "algorithm": "ECDSAP256SHA256", "bits": 256,
+             "privatekey": "Private-key-format: v1.2\nAlgorithm: 13 (ECDSAP256SHA256)\nPrivateKey: AAAA\n"},
+            {"type": "Cryptokey", "id": 2, "keytype": "zsk", "active": false, "dnskey": "256 3 13 BBBB"}
+        ]
+        "#;
+
+        let keys: Vec<Cryptokey> = serde_json::from_str(src).unwrap();
+
+        assert_eq!(serde_json::to_value(keys[0].new_cryptokey().unwrap()).unwrap(), serde_json::json!({
+            "keytype": "csk",
+            "active": true,
+            "published": false,
+            "privatekey": "Private-key-format: v1.2\nAlgorithm: 13 (ECDSAP256SHA256)\nPrivateKey: AAAA\n",
+        }));
+        assert!(keys[1].new_cryptokey().is_none());
+        assert!(keys[1].published());
+    }
+}
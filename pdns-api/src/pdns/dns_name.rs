@@ -0,0 +1,265 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+const MAX_LABEL_LENGTH: usize = 63;
+const MAX_NAME_LENGTH: usize = 253;
+const APEX: &str = "@";
+
+/// Fully qualified domain name, always kept in absolute form with a trailing dot.
+/// Comparison ignores the ASCII case of the labels.
+#[derive(Debug, Clone)]
+pub struct DnsName {
+    name: String,
+}
+
+impl DnsName {
+    /// Create an absolute name, appending the root label if missing
+    pub fn absolute(name: &str) -> DnsName {
+        match name.ends_with(".") {
+            true => DnsName { name: name.to_string() },
+            false => DnsName { name: format!("{}.", name) },
+        }
+    }
+
+    /// Resolve a record key relative to the zone. '@' denotes the zone apex, keys with a trailing
+    /// dot are absolute. Keys without a trailing dot are relative to the zone unless they already
+    /// end with the zone name.
+    pub fn from_record_key(key: &str, zone: &DnsName) -> DnsName {
+        if key == APEX {
+            return zone.clone();
+        }
+        if key.ends_with(".") {
+            return DnsName::absolute(key);
+        }
+
+        let candidate = DnsName::absolute(key);
+
+        match candidate.is_in_zone(zone) {
+            true => candidate,
+            false => DnsName::absolute(&format!("{}.{}", key, zone.name)),
+        }
+    }
+
+    /// Whether the name equals the zone name or lies below it, comparing whole labels
+    pub fn is_in_zone(&self, zone: &DnsName) -> bool {
+        let labels = self.labels();
+        let zone_labels = zone.labels();
+
+        labels.len() >= zone_labels.len()
+            && labels[labels.len() - zone_labels.len()..]
+            .iter()
+            .zip(zone_labels.iter())
+            .all(|(label, zone_label)| label.eq_ignore_ascii_case(zone_label))
+    }
+
+    /// Whether the name is the apex of the zone
+    pub fn is_apex_of(&self, zone: &DnsName) -> bool {
+        self == zone
+    }
+
+    /// Name relative to the zone, '@' for the apex. Names outside the zone are returned as absolute names.
+    pub fn relative_to(&self, zone: &DnsName) -> String {
+        if self.is_apex_of(zone) {
+            APEX.to_string()
+        } else if self.is_in_zone(zone) {
+            self.labels()[..self.label_count() - zone.label_count()].join(".")
+        } else {
+            self.name.clone()
+        }
+    }
+
+    pub fn label_count(&self) -> usize {
+        self.labels().len()
+    }
+
+    fn labels(&self) -> Vec<&str> {
+        match self.name.as_str() {
+            "." => Vec::new(),
+            name => name.trim_end_matches(".").split(".").collect(),
+        }
+    }
+}
+
+impl PartialEq for DnsName {
+    fn eq(&self, other: &Self) -> bool {
+        self.name.eq_ignore_ascii_case(&other.name)
+    }
+}
+
+impl Eq for DnsName {}
+
+impl Hash for DnsName {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.to_ascii_lowercase().hash(state)
+    }
+}
+
+impl Display for DnsName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", &self.name)
+    }
+}
+
+/// Convert a domain name, possibly containing Unicode labels, into its ASCII form as used
+/// on the wire and by the PowerDNS API. Unicode labels are encoded in punycode.
+pub fn to_ascii_name(name: &str) -> Result<String, String> {
+    if name == APEX {
+        return Ok(name.to_string());
+    }
+
+    let absolute = name.ends_with(".");
+
+    match idna::domain_to_ascii(name.strip_suffix(".").unwrap_or(name)) {
+        Ok(ascii_name) => {
+            verify_ascii_name(&ascii_name)?;
+
+            match absolute {
+                true => Ok(format!("{}.", ascii_name)),
+                false => Ok(ascii_name),
+            }
+        }
+        Err(errors) => Err(format!("IDNA conversion failed: {:?}", errors)),
+    }
+}
+
+/// Convert a domain name in ASCII form into its Unicode representation
+pub fn to_unicode_name(name: &str) -> String {
+    idna::domain_to_unicode(name).0
+}
+
+/// Format a domain name showing the Unicode form next to the ASCII form of internationalised names
+pub fn display_name(name: &str) -> String {
+    let unicode_name = to_unicode_name(name);
+
+    match unicode_name == name {
+        true => name.to_string(),
+        false => format!("{} ({})", name, unicode_name),
+    }
+}
+
+/// Verify a domain name in ASCII form against the label and name length limits of RFC 1035.
+/// Labels consist of letters, digits, hyphens and underscores, a single '*' label denotes a wildcard.
+pub fn verify_ascii_name(name: &str) -> Result<(), String> {
+    let name = name.strip_suffix(".").unwrap_or(name);
+
+    if name.is_empty() {
+        return Err("empty name".to_string());
+    }
+    if name.len() > MAX_NAME_LENGTH {
+        return Err(format!("name exceeds {} characters", MAX_NAME_LENGTH));
+    }
+
+    for label in name.split(".") {
+        if label.is_empty() {
+            return Err("empty label".to_string());
+        }
+        if label.len() > MAX_LABEL_LENGTH {
+            return Err(format!("label '{}' exceeds {} characters", label, MAX_LABEL_LENGTH));
+        }
+        if label == "*" {
+            continue;
+        }
+        if label.starts_with("-") || label.ends_with("-") {
+            return Err(format!("label '{}' starts or ends with a hyphen", label));
+        }
+        if !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(format!("label '{}' contains invalid characters", label));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pdns::dns_name::{display_name, DnsName, to_ascii_name, to_unicode_name, verify_ascii_name};
+
+    #[test]
+    fn should_resolve_apex_key() {
+        let zone = DnsName::absolute("ccsac.de");
+
+        assert_eq!(DnsName::from_record_key("@", &zone).to_string(), "ccsac.de.".to_string());
+        assert_eq!(DnsName::from_record_key("@", &zone).is_apex_of(&zone), true)
+    }
+
+    #[test]
+    fn should_resolve_relative_and_absolute_keys() {
+        let zone = DnsName::absolute("ccsac.de.");
+
+        assert_eq!(DnsName::from_record_key("www", &zone).to_string(), "www.ccsac.de.".to_string());
+        assert_eq!(DnsName::from_record_key("www.ccsac.de", &zone).to_string(), "www.ccsac.de.".to_string());
+        assert_eq!(DnsName::from_record_key("www.CCSAC.de.", &zone).to_string(), "www.CCSAC.de.".to_string());
+        assert_eq!(DnsName::from_record_key("myccsac.de", &zone).to_string(), "myccsac.de.ccsac.de.".to_string());
+        assert_eq!(DnsName::from_record_key("ccsac.de.evil", &zone).to_string(), "ccsac.de.evil.ccsac.de.".to_string())
+    }
+
+    #[test]
+    fn should_compare_names_ignoring_case() {
+        assert_eq!(DnsName::absolute("WWW.ccsac.de"), DnsName::absolute("www.CCSAC.de."));
+        assert_ne!(DnsName::absolute("www.ccsac.de"), DnsName::absolute("ww.ccsac.de"))
+    }
+
+    #[test]
+    fn should_check_zone_membership_by_labels() {
+        let zone = DnsName::absolute("ccsac.de");
+
+        assert_eq!(DnsName::absolute("www.ccsac.de.").is_in_zone(&zone), true);
+        assert_eq!(DnsName::absolute("CCSAC.DE").is_in_zone(&zone), true);
+        assert_eq!(DnsName::absolute("myccsac.de").is_in_zone(&zone), false);
+        assert_eq!(DnsName::absolute("ccsac.de.evil").is_in_zone(&zone), false)
+    }
+
+    #[test]
+    fn should_build_relative_name() {
+        let zone = DnsName::absolute("ccsac.de");
+
+        assert_eq!(DnsName::absolute("a.b.ccsac.de").relative_to(&zone), "a.b".to_string());
+        assert_eq!(DnsName::absolute("ccsac.de").relative_to(&zone), "@".to_string());
+        assert_eq!(DnsName::absolute("example.com").relative_to(&zone), "example.com.".to_string())
+    }
+
+    #[test]
+    fn should_convert_unicode_name_to_punycode() {
+        assert_eq!(to_ascii_name("bücher.de"), Ok("xn--bcher-kva.de".to_string()));
+        assert_eq!(to_ascii_name("Bücher.de."), Ok("xn--bcher-kva.de.".to_string()))
+    }
+
+    #[test]
+    fn should_convert_punycode_to_unicode_name() {
+        assert_eq!(to_unicode_name("xn--bcher-kva.de"), "bücher.de".to_string());
+        assert_eq!(display_name("xn--bcher-kva.de"), "xn--bcher-kva.de (bücher.de)".to_string());
+        assert_eq!(display_name("ccsac.de"), "ccsac.de".to_string())
+    }
+
+    #[test]
+    fn should_accept_long_top_level_domain() {
+        assert_eq!(verify_ascii_name("ccsac.technology"), Ok(()))
+    }
+
+    #[test]
+    fn should_accept_service_and_wildcard_labels() {
+        assert_eq!(verify_ascii_name("_sip._tcp.ccsac.de."), Ok(()));
+        assert_eq!(verify_ascii_name("*.ccsac.de"), Ok(()))
+    }
+
+    #[test]
+    fn should_fail_names_exceeding_limits() {
+        assert_eq!(verify_ascii_name(&format!("{}.de", "a".repeat(64))).is_err(), true);
+        assert_eq!(verify_ascii_name(&format!("{}.de", vec!["abcdefghi"; 26].join("."))).is_err(), true);
+        assert_eq!(verify_ascii_name("-ccsac.de").is_err(), true);
+        assert_eq!(verify_ascii_name("ccsac..de").is_err(), true)
+    }
+}
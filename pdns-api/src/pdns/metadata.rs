@@ -0,0 +1,77 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Kinds mirrored by zone properties, the metadata endpoint refuses to modify them
+const PROTECTED_KINDS: [&'static str; 8] = ["API-RECTIFY", "AXFR-MASTER-TSIG", "LUA-AXFR-SCRIPT", "NSEC3NARROW",
+    "NSEC3PARAM", "PRESIGNED", "SOA-EDIT-API", "TSIG-ALLOW-AXFR"];
+
+/// Domain metadata entry of a zone, e.g. ALSO-NOTIFY with its values
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Metadata {
+    kind: String,
+    metadata: Vec<String>,
+}
+
+impl Metadata {
+    pub fn new(kind: &String, metadata: &Vec<String>) -> Metadata {
+        Metadata {
+            kind: kind.clone(),
+            metadata: metadata.clone(),
+        }
+    }
+
+    pub fn kind(&self) -> String {
+        self.kind.clone()
+    }
+
+    pub fn metadata(&self) -> Vec<String> {
+        self.metadata.clone()
+    }
+
+    /// True if the kind can only be changed through the zone itself
+    pub fn is_protected(&self) -> bool {
+        PROTECTED_KINDS.contains(&self.kind.as_str())
+    }
+}
+
+impl Display for Metadata {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(kind: {}, metadata: {})", &self.kind, self.metadata.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pdns::metadata::Metadata;
+
+    #[test]
+    fn should_deserialize_metadata() {
+        let src = r#"
+        [
+            {"type": "Metadata", "kind": "ALSO-NOTIFY", "metadata": ["192.0.2.1", "192.0.2.2"]},
+            {"type": "Metadata", "kind": "SOA-EDIT-API", "metadata": ["DEFAULT"]}
+        ]
+        "#;
+
+        let metadata: Vec<Metadata> = serde_json::from_str(src).unwrap();
+
+        assert_eq!(metadata[0], Metadata::new(&"ALSO-NOTIFY".to_string(),
+                                              &vec!["192.0.2.1".to_string(), "192.0.2.2".to_string()]));
+        assert!(!metadata[0].is_protected());
+        assert!(metadata[1].is_protected());
+    }
+}
@@ -15,4 +15,17 @@ pub mod server;
 pub mod struct_type;
 pub mod error;
 pub mod common;
-pub mod zone;
\ No newline at end of file
+pub mod zone;
+pub mod soa;
+pub mod reverse;
+pub mod dns_name;
+pub mod statistics;
+pub mod config;
+pub mod cache;
+pub mod recursor;
+pub mod version;
+pub mod catalog;
+pub mod autoprimary;
+pub mod metadata;
+pub mod cryptokey;
+pub mod backup;
@@ -0,0 +1,178 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::pdns::struct_type::StructType;
+
+/// Zone of the recursor, either served from local records or forwarded to other servers
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecursorZone {
+    id: String,
+    name: String,
+    #[serde(rename = "type")]
+    type_id: Option<StructType>,
+    url: Option<String>,
+    kind: RecursorZoneKind,
+    #[serde(default)]
+    servers: Vec<String>,
+    #[serde(default)]
+    recursion_desired: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NewForwardZone {
+    name: String,
+    #[serde(rename = "type")]
+    type_id: StructType,
+    kind: RecursorZoneKind,
+    servers: Vec<String>,
+    recursion_desired: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RecursorZoneKind {
+    Native,
+    Forwarded,
+}
+
+/// Transfer state of a response policy zone
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RpzStatistics {
+    last_update: u64,
+    records: u64,
+    serial: u64,
+    transfers_failed: u64,
+    transfers_full: u64,
+    transfers_success: u64,
+}
+
+impl RecursorZone {
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn kind(&self) -> RecursorZoneKind {
+        self.kind.clone()
+    }
+
+    pub fn servers(&self) -> Vec<String> {
+        self.servers.clone()
+    }
+
+    pub fn recursion_desired(&self) -> bool {
+        self.recursion_desired
+    }
+}
+
+impl NewForwardZone {
+    /// Zone forwarding queries to the servers, given as IP address with optional port
+    pub fn new(name: &String, servers: &Vec<String>, recursion_desired: bool) -> NewForwardZone {
+        NewForwardZone {
+            name: name.clone(),
+            type_id: StructType::Zone,
+            kind: RecursorZoneKind::Forwarded,
+            servers: servers.clone(),
+            recursion_desired,
+        }
+    }
+}
+
+impl RpzStatistics {
+    /// Seconds since the epoch of the last zone transfer
+    pub fn last_update(&self) -> u64 {
+        self.last_update
+    }
+
+    pub fn records(&self) -> u64 {
+        self.records
+    }
+
+    pub fn serial(&self) -> u64 {
+        self.serial
+    }
+
+    pub fn transfers_failed(&self) -> u64 {
+        self.transfers_failed
+    }
+
+    pub fn transfers_full(&self) -> u64 {
+        self.transfers_full
+    }
+
+    pub fn transfers_success(&self) -> u64 {
+        self.transfers_success
+    }
+}
+
+impl Display for RecursorZone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}) -> [{}]", &self.name, &self.kind, self.servers.join(", "))
+    }
+}
+
+impl Display for RecursorZoneKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecursorZoneKind::Native => write!(f, "Native"),
+            RecursorZoneKind::Forwarded => write!(f, "Forwarded"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::pdns::recursor::{RecursorZone, RecursorZoneKind, RpzStatistics};
+
+    #[test]
+    fn should_deserialize_recursor_zones() {
+        let src = r#"
+        [
+            {"id": "example.com.", "kind": "Forwarded", "name": "example.com.", "records": [],
+             "recursion_desired": true, "servers": ["192.0.2.1:53", "192.0.2.2:5300"], "type": "Zone",
+             "url": "/api/v1/servers/localhost/zones/example.com."},
+            {"id": "internal.", "kind": "Native", "name": "internal.", "records": [], "servers": [],
+             "type": "Zone", "url": "/api/v1/servers/localhost/zones/internal."}
+        ]"#;
+        let zones = serde_json::from_str::<Vec<RecursorZone>>(src).unwrap();
+
+        assert_eq!(zones[0].kind(), RecursorZoneKind::Forwarded);
+        assert_eq!(zones[0].servers(), vec!["192.0.2.1:53".to_string(), "192.0.2.2:5300".to_string()]);
+        assert!(zones[0].recursion_desired());
+        assert_eq!(zones[1].kind(), RecursorZoneKind::Native);
+        assert!(!zones[1].recursion_desired())
+    }
+
+    #[test]
+    fn should_deserialize_rpz_statistics() {
+        let src = r#"
+        {
+            "rpz.example.": {"last_update": 1637663612, "records": 12, "serial": 2021112301,
+                             "transfers_failed": 1, "transfers_full": 3, "transfers_success": 4}
+        }"#;
+        let statistics = serde_json::from_str::<BTreeMap<String, RpzStatistics>>(src).unwrap();
+        let rpz = statistics.get("rpz.example.").unwrap();
+
+        assert_eq!(rpz.serial(), 2021112301);
+        assert_eq!(rpz.records(), 12);
+        assert_eq!(rpz.transfers_success(), 4)
+    }
+}
@@ -0,0 +1,310 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use crate::pdns::dns_name::DnsName;
+
+const IPV4_REVERSE_DOMAIN: &'static str = "in-addr.arpa.";
+const IPV6_REVERSE_DOMAIN: &'static str = "ip6.arpa.";
+
+/// Build the name of the PTR record pointing back to the forward name of an address,
+/// e.g. '4.26.16.172.in-addr.arpa.' for 172.16.26.4
+pub fn reverse_pointer_name(address: &IpAddr) -> String {
+    match address {
+        IpAddr::V4(address) => {
+            let mut labels: Vec<String> = address.octets()
+                .iter()
+                .rev()
+                .map(|octet| octet.to_string())
+                .collect();
+
+            labels.push(IPV4_REVERSE_DOMAIN.to_string());
+            labels.join(".")
+        }
+        IpAddr::V6(address) => {
+            let mut labels: Vec<String> = address.octets()
+                .iter()
+                .rev()
+                .flat_map(|octet| vec![octet & 0x0f, octet >> 4])
+                .map(|nibble| format!("{:x}", nibble))
+                .collect();
+
+            labels.push(IPV6_REVERSE_DOMAIN.to_string());
+            labels.join(".")
+        }
+    }
+}
+
+/// Parse a network prefix like '172.16.26.0/24' or '2001:db8::/48'. Prefixes with host bits set are rejected.
+pub fn parse_network_prefix(cidr: &str) -> Option<(IpAddr, u8)> {
+    let (address, prefix_len) = cidr.split_once("/")?;
+    let address = IpAddr::from_str(address).ok()?;
+    let prefix_len = prefix_len.parse::<u8>().ok()?;
+
+    match address {
+        IpAddr::V4(network) if prefix_len > 0 && prefix_len <= 32 => {
+            let bits = u32::from(network);
+
+            match bits & !ipv4_mask(prefix_len) {
+                0 => Some((address, prefix_len)),
+                _ => None,
+            }
+        }
+        IpAddr::V6(network) if prefix_len > 0 && prefix_len <= 128 => {
+            let bits = u128::from(network);
+
+            match bits & !ipv6_mask(prefix_len) {
+                0 => Some((address, prefix_len)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Compute the reverse zones covering a network prefix.
+/// IPv4 prefixes not ending on an octet boundary are covered by several zones, prefixes longer
+/// than /24 are delegated in the RFC 2317 style '<first address>-<prefix length>.26.16.172.in-addr.arpa.'.
+/// The CNAME records delegating the addresses from the parent zone to such a child zone are not covered.
+/// IPv6 prefixes not ending on a nibble boundary are covered by several zones.
+pub fn reverse_zone_names(network: &IpAddr, prefix_len: u8) -> Vec<String> {
+    match network {
+        IpAddr::V4(network) if prefix_len > 24 => {
+            let octets = network.octets();
+
+            vec![format!("{}-{}.{}.{}.{}.{}", octets[3], prefix_len,
+                         octets[2], octets[1], octets[0], IPV4_REVERSE_DOMAIN)]
+        }
+        IpAddr::V4(network) => {
+            let zone_octets = prefix_len.div_ceil(8) as usize;
+            let zone_count = 1u32 << (zone_octets as u32 * 8 - prefix_len as u32);
+            let step = 1u32 << (32 - zone_octets as u32 * 8);
+
+            (0..zone_count)
+                .map(|index| Ipv4Addr::from(u32::from(*network) + index * step))
+                .map(|address| {
+                    let mut labels: Vec<String> = address.octets()[..zone_octets]
+                        .iter()
+                        .rev()
+                        .map(|octet| octet.to_string())
+                        .collect();
+
+                    labels.push(IPV4_REVERSE_DOMAIN.to_string());
+                    labels.join(".")
+                })
+                .collect()
+        }
+        IpAddr::V6(network) => {
+            let zone_nibbles = prefix_len.div_ceil(4) as usize;
+            let zone_count = 1u128 << (zone_nibbles as u32 * 4 - prefix_len as u32);
+            let step = 1u128 << (128 - zone_nibbles as u32 * 4);
+
+            (0..zone_count)
+                .map(|index| Ipv6Addr::from(u128::from(*network) + index * step))
+                .map(|address| {
+                    let nibbles: Vec<u8> = address.octets()
+                        .iter()
+                        .flat_map(|octet| vec![octet >> 4, octet & 0x0f])
+                        .collect();
+                    let mut labels: Vec<String> = nibbles[..zone_nibbles]
+                        .iter()
+                        .rev()
+                        .map(|nibble| format!("{:x}", nibble))
+                        .collect();
+
+                    labels.push(IPV6_REVERSE_DOMAIN.to_string());
+                    labels.join(".")
+                })
+                .collect()
+        }
+    }
+}
+
+/// Find the most specific zone containing the given reverse name
+pub fn find_reverse_zone(reverse_name: &String, zone_names: &Vec<String>) -> Option<String> {
+    let reverse_name = DnsName::absolute(reverse_name);
+
+    zone_names.iter()
+        .filter(|zone_name| reverse_name.is_in_zone(&DnsName::absolute(zone_name)))
+        .max_by_key(|zone_name| DnsName::absolute(zone_name).label_count())
+        .cloned()
+}
+
+/// Find the reverse zone of an address and the name of its PTR record in that zone. An address in
+/// an RFC 2317 classless zone gets its PTR record in that zone, e.g. '130.128-26.26.16.172.in-addr.arpa.'
+/// for 172.16.26.130 in '128-26.26.16.172.in-addr.arpa.', unless a zone of the address itself exists.
+pub fn find_reverse_pointer(address: &IpAddr, zone_names: &Vec<String>) -> Option<(String, String)> {
+    let reverse_name = reverse_pointer_name(address);
+    let zone_name = find_reverse_zone(&reverse_name, zone_names);
+
+    if let IpAddr::V4(address) = address {
+        if let Some(classless_zone_name) = find_classless_zone(address, zone_names) {
+            let classless_zone = DnsName::absolute(&classless_zone_name);
+
+            match &zone_name {
+                Some(zone_name) if DnsName::absolute(zone_name).label_count() >= classless_zone.label_count() => {}
+                _ => return Some((classless_zone_name, format!("{}.{}", address.octets()[3], classless_zone))),
+            }
+        }
+    }
+
+    zone_name.map(|zone_name| (zone_name, reverse_name))
+}
+
+/// Find the most specific RFC 2317 classless zone containing the address
+fn find_classless_zone(address: &Ipv4Addr, zone_names: &[String]) -> Option<String> {
+    zone_names.iter()
+        .filter_map(|zone_name| classless_network(zone_name).map(|network| (zone_name, network)))
+        .filter(|(_, (network, prefix_len))| u32::from(*address) & ipv4_mask(*prefix_len) == u32::from(*network))
+        .max_by_key(|(_, (_, prefix_len))| *prefix_len)
+        .map(|(zone_name, _)| zone_name.clone())
+}
+
+/// Network of an RFC 2317 classless zone named '<first address>-<prefix length>.26.16.172.in-addr.arpa.'
+fn classless_network(zone_name: &str) -> Option<(Ipv4Addr, u8)> {
+    let labels: Vec<String> = DnsName::absolute(zone_name).to_string()
+        .to_lowercase()
+        .strip_suffix(IPV4_REVERSE_DOMAIN)?
+        .trim_end_matches('.')
+        .split('.')
+        .map(|label| label.to_string())
+        .collect();
+
+    match labels.as_slice() {
+        [range, octet_3, octet_2, octet_1] => {
+            let (first, prefix_len) = range.split_once('-')?;
+            let network = Ipv4Addr::new(octet_1.parse().ok()?, octet_2.parse().ok()?,
+                                        octet_3.parse().ok()?, first.parse().ok()?);
+            let prefix_len = prefix_len.parse::<u8>().ok()?;
+
+            match prefix_len > 24 && prefix_len <= 32 && u32::from(network) & !ipv4_mask(prefix_len) == 0 {
+                true => Some((network, prefix_len)),
+                false => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn ipv4_mask(prefix_len: u8) -> u32 {
+    u32::MAX.checked_shl(32 - prefix_len as u32).unwrap_or(0)
+}
+
+fn ipv6_mask(prefix_len: u8) -> u128 {
+    u128::MAX.checked_shl(128 - prefix_len as u32).unwrap_or(0)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+    use std::str::FromStr;
+
+    use crate::pdns::reverse::{find_reverse_pointer, find_reverse_zone, parse_network_prefix, reverse_pointer_name,
+                               reverse_zone_names};
+
+    #[test]
+    fn should_build_ipv4_reverse_pointer_name() {
+        assert_eq!(reverse_pointer_name(&IpAddr::from_str("172.16.26.4").unwrap()),
+                   "4.26.16.172.in-addr.arpa.".to_string())
+    }
+
+    #[test]
+    fn should_build_ipv6_reverse_pointer_name() {
+        assert_eq!(reverse_pointer_name(&IpAddr::from_str("2001:db8::567:89ab").unwrap()),
+                   "b.a.9.8.7.6.5.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.b.d.0.1.0.0.2.ip6.arpa.".to_string())
+    }
+
+    #[test]
+    fn should_find_most_specific_reverse_zone() {
+        let zones = vec![
+            "16.172.in-addr.arpa.".to_string(),
+            "26.16.172.in-addr.arpa.".to_string(),
+            "6.16.172.in-addr.arpa.".to_string(),
+            "ccsac.de.".to_string(),
+        ];
+
+        assert_eq!(find_reverse_zone(&"4.26.16.172.in-addr.arpa.".to_string(), &zones),
+                   Some("26.16.172.in-addr.arpa.".to_string()));
+        assert_eq!(find_reverse_zone(&"4.27.16.172.in-addr.arpa.".to_string(), &zones),
+                   Some("16.172.in-addr.arpa.".to_string()));
+        assert_eq!(find_reverse_zone(&"4.26.16.10.in-addr.arpa.".to_string(), &zones), None)
+    }
+
+    #[test]
+    fn should_find_pointer_in_classless_reverse_zone() {
+        let zones = vec![
+            "26.16.172.in-addr.arpa.".to_string(),
+            "128-26.26.16.172.in-addr.arpa.".to_string(),
+            "192-27.26.16.172.in-addr.arpa.".to_string(),
+        ];
+        let pointer = |address: &str| find_reverse_pointer(&IpAddr::from_str(address).unwrap(), &zones);
+
+        assert_eq!(pointer("172.16.26.130"), Some(("128-26.26.16.172.in-addr.arpa.".to_string(),
+                                                   "130.128-26.26.16.172.in-addr.arpa.".to_string())));
+        assert_eq!(pointer("172.16.26.191"), Some(("128-26.26.16.172.in-addr.arpa.".to_string(),
+                                                   "191.128-26.26.16.172.in-addr.arpa.".to_string())));
+        assert_eq!(pointer("172.16.26.200"), Some(("192-27.26.16.172.in-addr.arpa.".to_string(),
+                                                   "200.192-27.26.16.172.in-addr.arpa.".to_string())));
+        assert_eq!(pointer("172.16.26.4"), Some(("26.16.172.in-addr.arpa.".to_string(),
+                                                 "4.26.16.172.in-addr.arpa.".to_string())));
+        assert_eq!(pointer("10.0.0.1"), None)
+    }
+
+    #[test]
+    fn should_reject_prefix_with_host_bits() {
+        assert_eq!(parse_network_prefix("172.16.26.1/24"), None);
+        assert_eq!(parse_network_prefix("172.16.26.0/33"), None);
+        assert_eq!(parse_network_prefix("2001:db8::1/64"), None)
+    }
+
+    #[test]
+    fn should_compute_octet_aligned_ipv4_reverse_zone() {
+        let (network, prefix_len) = parse_network_prefix("172.16.26.0/24").unwrap();
+
+        assert_eq!(reverse_zone_names(&network, prefix_len), vec!["26.16.172.in-addr.arpa.".to_string()])
+    }
+
+    #[test]
+    fn should_compute_ipv4_reverse_zones_for_unaligned_prefix() {
+        let (network, prefix_len) = parse_network_prefix("172.16.26.0/23").unwrap();
+
+        assert_eq!(reverse_zone_names(&network, prefix_len), vec![
+            "26.16.172.in-addr.arpa.".to_string(),
+            "27.16.172.in-addr.arpa.".to_string(),
+        ])
+    }
+
+    #[test]
+    fn should_compute_classless_ipv4_reverse_zone() {
+        let (network, prefix_len) = parse_network_prefix("172.16.26.128/26").unwrap();
+
+        assert_eq!(reverse_zone_names(&network, prefix_len), vec!["128-26.26.16.172.in-addr.arpa.".to_string()])
+    }
+
+    #[test]
+    fn should_compute_ipv6_reverse_zones() {
+        let (network, prefix_len) = parse_network_prefix("2001:db8::/32").unwrap();
+
+        assert_eq!(reverse_zone_names(&network, prefix_len), vec!["8.b.d.0.1.0.0.2.ip6.arpa.".to_string()]);
+
+        let (network, prefix_len) = parse_network_prefix("2001:db8::/31").unwrap();
+
+        assert_eq!(reverse_zone_names(&network, prefix_len), vec![
+            "8.b.d.0.1.0.0.2.ip6.arpa.".to_string(),
+            "9.b.d.0.1.0.0.2.ip6.arpa.".to_string(),
+        ])
+    }
+}
@@ -17,6 +17,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::pdns::struct_type::StructType;
 use crate::pdns::common::PowerDnsPayload;
+use crate::pdns::version::ServerVersion;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Server {
@@ -30,7 +31,7 @@ pub struct Server {
     zones_url: String,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DaemonType {
     #[serde(rename = "recursor")]
     Recursor,
@@ -56,6 +57,11 @@ impl Server {
         self.version.clone()
     }
 
+    /// Parsed version, None if the server reports a version not following the release scheme
+    pub fn server_version(&self) -> Option<ServerVersion> {
+        ServerVersion::parse(&self.version)
+    }
+
     pub fn url(&self) -> String {
         self.url.clone()
     }
@@ -85,7 +91,7 @@ impl Display for Server {
 impl Display for DaemonType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            DaemonType::Authoritative => write!(f, "AUTHORATIVE"),
+            DaemonType::Authoritative => write!(f, "AUTHORITATIVE"),
             DaemonType::Recursor => write!(f, "RECURSOR")
         }
     }
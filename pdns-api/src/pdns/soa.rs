@@ -0,0 +1,185 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::{Display, Formatter};
+
+use chrono::{DateTime, Utc};
+
+use crate::pdns::zone::{Changetype, Record, Rrset, RrsetType, Zone};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SerialPolicy {
+    None,
+    Increment,
+    DateCounter,
+    Epoch,
+}
+
+impl SerialPolicy {
+    pub fn from_name(name: &str) -> Option<SerialPolicy> {
+        match name {
+            "none" => Some(SerialPolicy::None),
+            "increment" => Some(SerialPolicy::Increment),
+            "date-counter" => Some(SerialPolicy::DateCounter),
+            "epoch" => Some(SerialPolicy::Epoch),
+            _ => None,
+        }
+    }
+
+    /// Serial of a newly created zone. Without a policy, the date based serial is used.
+    pub fn initial_serial(&self, now: &DateTime<Utc>) -> u32 {
+        match self {
+            SerialPolicy::Increment => 1,
+            SerialPolicy::Epoch => now.timestamp() as u32,
+            SerialPolicy::None | SerialPolicy::DateCounter => date_base(now) + 1,
+        }
+    }
+
+    /// Serial following the current serial of a zone, None if the serial is not managed.
+    pub fn next_serial(&self, current: u32, now: &DateTime<Utc>) -> Option<u32> {
+        match self {
+            SerialPolicy::None => None,
+            SerialPolicy::Increment => Some(current.wrapping_add(1)),
+            SerialPolicy::DateCounter => {
+                let base = date_base(now);
+
+                if current < base {
+                    Some(base + 1)
+                } else {
+                    Some(current.wrapping_add(1))
+                }
+            }
+            SerialPolicy::Epoch => {
+                let epoch = now.timestamp() as u32;
+
+                if current < epoch {
+                    Some(epoch)
+                } else {
+                    Some(current.wrapping_add(1))
+                }
+            }
+        }
+    }
+}
+
+impl Display for SerialPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SerialPolicy::None => write!(f, "none"),
+            SerialPolicy::Increment => write!(f, "increment"),
+            SerialPolicy::DateCounter => write!(f, "date-counter"),
+            SerialPolicy::Epoch => write!(f, "epoch"),
+        }
+    }
+}
+
+/// Build the SOA rrset replacing the zone SOA with a bumped serial.
+/// Zones having SOA-EDIT-API set get their serial bumped by PowerDNS, so nothing is returned for them.
+pub fn next_soa_rrset(zone: &Zone, policy: &SerialPolicy, now: &DateTime<Utc>) -> Option<Rrset> {
+    match zone.soa_edit_api().as_str() {
+        "" | "OFF" => (),
+        _ => return None,
+    }
+
+    let soa_rrset = zone.rrsets()
+        .into_iter()
+        .find(|rrset| rrset.type_id() == RrsetType::Soa && rrset.name() == zone.name())?;
+    let record = soa_rrset.records().into_iter().next()?;
+    let serial = soa_serial(&record.content())?;
+    let content = replace_soa_serial(&record.content(), policy.next_serial(serial, now)?)?;
+
+    Some(Rrset::new(&soa_rrset.name(), RrsetType::Soa,
+                    &Some(Changetype::Replace),
+                    &soa_rrset.ttl(),
+                    &vec![Record::new(&content, record.disabled())],
+                    &Vec::new()))
+}
+
+/// Extract the serial from SOA record content 'mname rname serial refresh retry expire minimum'
+pub fn soa_serial(content: &String) -> Option<u32> {
+    let fields: Vec<&str> = content.split_whitespace().collect();
+
+    match fields.len() {
+        7 => fields[2].parse::<u32>().ok(),
+        _ => None,
+    }
+}
+
+pub fn replace_soa_serial(content: &String, serial: u32) -> Option<String> {
+    let mut fields: Vec<String> = content.split_whitespace().map(|field| field.to_string()).collect();
+
+    match fields.len() {
+        7 => {
+            fields[2] = serial.to_string();
+
+            Some(fields.join(" "))
+        }
+        _ => None,
+    }
+}
+
+fn date_base(now: &DateTime<Utc>) -> u32 {
+    now.format("%Y%m%d").to_string().parse::<u32>().unwrap() * 100
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use crate::pdns::soa::{replace_soa_serial, soa_serial, SerialPolicy};
+
+    #[test]
+    fn should_not_bump_serial_without_policy() {
+        let now = Utc.ymd(2021, 11, 2).and_hms(10, 0, 0);
+
+        assert_eq!(SerialPolicy::None.next_serial(2021110201, &now), None)
+    }
+
+    #[test]
+    fn should_increment_serial() {
+        let now = Utc.ymd(2021, 11, 2).and_hms(10, 0, 0);
+
+        assert_eq!(SerialPolicy::Increment.next_serial(41, &now), Some(42))
+    }
+
+    #[test]
+    fn should_start_date_counter_on_new_day() {
+        let now = Utc.ymd(2021, 11, 2).and_hms(10, 0, 0);
+
+        assert_eq!(SerialPolicy::DateCounter.next_serial(2021110105, &now), Some(2021110201))
+    }
+
+    #[test]
+    fn should_count_up_date_counter_on_same_day() {
+        let now = Utc.ymd(2021, 11, 2).and_hms(10, 0, 0);
+
+        assert_eq!(SerialPolicy::DateCounter.next_serial(2021110201, &now), Some(2021110202))
+    }
+
+    #[test]
+    fn should_use_epoch_serial() {
+        let now = Utc.ymd(2021, 11, 2).and_hms(10, 0, 0);
+
+        assert_eq!(SerialPolicy::Epoch.next_serial(1635840000, &now), Some(now.timestamp() as u32));
+        assert_eq!(SerialPolicy::Epoch.next_serial(2021110201, &now), Some(2021110202))
+    }
+
+    #[test]
+    fn should_replace_soa_serial() {
+        let content = "ccsac.de. root.ccsac.de. 2021110201 3600 1800 604800 600".to_string();
+
+        assert_eq!(soa_serial(&content), Some(2021110201));
+        assert_eq!(replace_soa_serial(&content, 2021110202),
+                   Some("ccsac.de. root.ccsac.de. 2021110202 3600 1800 604800 600".to_string()))
+    }
+}
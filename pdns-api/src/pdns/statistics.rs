@@ -0,0 +1,173 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// Entry of the statistics endpoint, discriminated by its "type" property
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Statistic {
+    StatisticItem(StatisticItem),
+    MapStatisticItem(MapStatisticItem),
+    RingStatisticItem(RingStatisticItem),
+}
+
+/// Single counter or gauge, the value is a number in string form
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StatisticItem {
+    name: String,
+    value: String,
+}
+
+/// Counters keyed by e.g. query type or response code
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MapStatisticItem {
+    name: String,
+    value: Vec<SimpleStatisticItem>,
+}
+
+/// Most frequent entries of a ring buffer like the top queried names
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RingStatisticItem {
+    name: String,
+    size: u64,
+    value: Vec<SimpleStatisticItem>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SimpleStatisticItem {
+    name: String,
+    value: String,
+}
+
+impl Statistic {
+    pub fn name(&self) -> String {
+        match self {
+            Statistic::StatisticItem(item) => item.name(),
+            Statistic::MapStatisticItem(item) => item.name(),
+            Statistic::RingStatisticItem(item) => item.name(),
+        }
+    }
+}
+
+impl StatisticItem {
+    pub fn new(name: &String, value: &String) -> StatisticItem {
+        StatisticItem {
+            name: name.clone(),
+            value: value.clone(),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn value(&self) -> String {
+        self.value.clone()
+    }
+}
+
+impl MapStatisticItem {
+    pub fn new(name: &String, value: &Vec<SimpleStatisticItem>) -> MapStatisticItem {
+        MapStatisticItem {
+            name: name.clone(),
+            value: value.clone(),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn value(&self) -> Vec<SimpleStatisticItem> {
+        self.value.clone()
+    }
+}
+
+impl RingStatisticItem {
+    pub fn new(name: &String, size: u64, value: &Vec<SimpleStatisticItem>) -> RingStatisticItem {
+        RingStatisticItem {
+            name: name.clone(),
+            size,
+            value: value.clone(),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn value(&self) -> Vec<SimpleStatisticItem> {
+        self.value.clone()
+    }
+}
+
+impl SimpleStatisticItem {
+    pub fn new(name: &String, value: &String) -> SimpleStatisticItem {
+        SimpleStatisticItem {
+            name: name.clone(),
+            value: value.clone(),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn value(&self) -> String {
+        self.value.clone()
+    }
+}
+
+impl Display for Statistic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Statistic::StatisticItem(item) => write!(f, "{}={}", &item.name, &item.value),
+            Statistic::MapStatisticItem(item) => write!(f, "{}=(map, {} entries)", &item.name, item.value.len()),
+            Statistic::RingStatisticItem(item) => write!(f, "{}=(ring, size {}, {} entries)",
+                                                         &item.name, item.size, item.value.len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pdns::statistics::{MapStatisticItem, RingStatisticItem, SimpleStatisticItem, Statistic, StatisticItem};
+
+    #[test]
+    fn should_deserialize_statistic_items() {
+        let src = r#"
+        [
+            { "name": "udp-queries", "type": "StatisticItem", "value": "4711" },
+            { "name": "response-by-qtype", "type": "MapStatisticItem", "value": [ { "name": "A", "value": "42" } ] },
+            { "name": "queries", "type": "RingStatisticItem", "size": 10000, "value": [ { "name": "example.com/A", "value": "7" } ] }
+        ]
+        "#;
+
+        let statistics: Vec<Statistic> = serde_json::from_str(src).unwrap();
+
+        assert_eq!(statistics, vec![
+            Statistic::StatisticItem(StatisticItem::new(&"udp-queries".to_string(), &"4711".to_string())),
+            Statistic::MapStatisticItem(MapStatisticItem::new(&"response-by-qtype".to_string(),
+                                                              &vec![SimpleStatisticItem::new(&"A".to_string(), &"42".to_string())])),
+            Statistic::RingStatisticItem(RingStatisticItem::new(&"queries".to_string(), 10000,
+                                                                &vec![SimpleStatisticItem::new(&"example.com/A".to_string(), &"7".to_string())])),
+        ])
+    }
+}
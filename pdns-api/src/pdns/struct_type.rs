@@ -19,6 +19,7 @@ pub enum StructType {
     None,
     Server,
     Zone,
+    ConfigSetting,
 }
 
 impl Display for StructType {
@@ -26,6 +27,7 @@ impl Display for StructType {
         match self {
             StructType::Server => write!(f, "SERVER"),
             StructType::Zone => write!(f, "ZONE"),
+            StructType::ConfigSetting => write!(f, "CONFIG_SETTING"),
             StructType::None => write!(f, "(pseudo)NONE"),
         }
     }
@@ -0,0 +1,200 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+
+/// Version reported by the server, e.g. '4.7.3' or '4.8.0-beta1'. Pre-releases compare equal
+/// to their release, as they already offer the features introduced with it.
+#[derive(Clone, Debug)]
+pub struct ServerVersion {
+    major: u32,
+    minor: u32,
+    patch: u32,
+    pre_release: Option<String>,
+}
+
+/// Features of the authoritative server not available in all supported versions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Capability {
+    ZoneExport,
+    Ixfr,
+    LuaRecords,
+    ProducerConsumerKinds,
+    CatalogZones,
+    Autoprimaries,
+}
+
+impl ServerVersion {
+    pub fn new(major: u32, minor: u32, patch: u32) -> ServerVersion {
+        ServerVersion {
+            major,
+            minor,
+            patch,
+            pre_release: None,
+        }
+    }
+
+    /// Parse 'major.minor.patch' with an optional '-pre-release' suffix, None for other schemes
+    /// like the version strings of builds from the master branch
+    pub fn parse(version: &str) -> Option<ServerVersion> {
+        let (release, pre_release) = match version.trim().split_once('-') {
+            Some((release, pre_release)) => (release, Some(pre_release.to_string())),
+            None => (version.trim(), None),
+        };
+        let numbers: Vec<u32> = release.split('.')
+            .map(|number| number.parse::<u32>().ok())
+            .collect::<Option<Vec<u32>>>()?;
+
+        match numbers.as_slice() {
+            [major, minor, patch] if *major > 0 => Some(ServerVersion {
+                major: *major,
+                minor: *minor,
+                patch: *patch,
+                pre_release,
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn major(&self) -> u32 {
+        self.major
+    }
+
+    pub fn minor(&self) -> u32 {
+        self.minor
+    }
+
+    pub fn patch(&self) -> u32 {
+        self.patch
+    }
+
+    pub fn pre_release(&self) -> Option<String> {
+        self.pre_release.clone()
+    }
+
+    pub fn supports(&self, capability: Capability) -> bool {
+        *self >= capability.required_version()
+    }
+}
+
+impl Capability {
+    /// Capability needed to create or switch to the zone kind, e.g. 'producer'
+    pub fn for_zone_kind(zone_kind: &str) -> Option<Capability> {
+        match zone_kind {
+            "producer" | "consumer" => Some(Capability::ProducerConsumerKinds),
+            _ => None,
+        }
+    }
+
+    /// Capability needed to serve records of the type, e.g. 'LUA'
+    pub fn for_record_type(record_type: &str) -> Option<Capability> {
+        match record_type {
+            "LUA" => Some(Capability::LuaRecords),
+            _ => None,
+        }
+    }
+
+    /// Capability needed to act on the zone metadata kind, e.g. 'IXFR' for incremental transfers into secondaries
+    pub fn for_metadata_kind(metadata_kind: &str) -> Option<Capability> {
+        match metadata_kind {
+            "IXFR" => Some(Capability::Ixfr),
+            _ => None,
+        }
+    }
+
+    /// Setting which must be enabled in addition to the version, e.g. 'enable-lua-records'
+    pub fn required_setting(&self) -> Option<&'static str> {
+        match self {
+            Capability::LuaRecords => Some("enable-lua-records"),
+            _ => None,
+        }
+    }
+
+    /// First release of the authoritative server offering the capability
+    pub fn required_version(&self) -> ServerVersion {
+        match self {
+            Capability::ZoneExport => ServerVersion::new(4, 1, 0),
+            Capability::Ixfr => ServerVersion::new(4, 1, 0),
+            Capability::LuaRecords => ServerVersion::new(4, 2, 0),
+            Capability::ProducerConsumerKinds => ServerVersion::new(4, 7, 0),
+            Capability::CatalogZones => ServerVersion::new(4, 7, 0),
+            Capability::Autoprimaries => ServerVersion::new(4, 7, 0),
+        }
+    }
+}
+
+impl PartialEq for ServerVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for ServerVersion {}
+
+impl PartialOrd for ServerVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ServerVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch).cmp(&(other.major, other.minor, other.patch))
+    }
+}
+
+impl Display for ServerVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.pre_release {
+            Some(pre_release) => write!(f, "{}.{}.{}-{}", self.major, self.minor, self.patch, pre_release),
+            None => write!(f, "{}.{}.{}", self.major, self.minor, self.patch),
+        }
+    }
+}
+
+impl Display for Capability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Capability::ZoneExport => write!(f, "zone export"),
+            Capability::Ixfr => write!(f, "IXFR"),
+            Capability::LuaRecords => write!(f, "LUA records"),
+            Capability::ProducerConsumerKinds => write!(f, "producer and consumer zone kinds"),
+            Capability::CatalogZones => write!(f, "catalog zones"),
+            Capability::Autoprimaries => write!(f, "autoprimary management"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pdns::version::{Capability, ServerVersion};
+
+    #[test]
+    fn should_parse_release_and_pre_release_versions() {
+        assert_eq!(ServerVersion::parse("4.9.1"), Some(ServerVersion::new(4, 9, 1)));
+        assert_eq!(ServerVersion::parse("4.8.0-beta1").unwrap().pre_release(), Some("beta1".to_string()));
+        assert_eq!(ServerVersion::parse("0.0.20230419.0.master.g6d8bbe1"), None);
+        assert_eq!(ServerVersion::parse("4.7"), None)
+    }
+
+    #[test]
+    fn should_gate_capabilities_on_version() {
+        assert!(!ServerVersion::parse("4.4.1").unwrap().supports(Capability::CatalogZones));
+        assert!(ServerVersion::parse("4.7.0-alpha1").unwrap().supports(Capability::CatalogZones));
+        assert!(ServerVersion::parse("4.4.1").unwrap().supports(Capability::LuaRecords));
+        assert!(!ServerVersion::parse("4.6.3").unwrap().supports(Capability::ProducerConsumerKinds));
+        assert!(!ServerVersion::parse("4.0.9").unwrap().supports(Capability::Ixfr));
+        assert!(ServerVersion::parse("4.1.0").unwrap().supports(Capability::Ixfr))
+    }
+}
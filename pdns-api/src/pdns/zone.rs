@@ -0,0 +1,666 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::{Display, Formatter};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+use crate::pdns::common::PowerDnsPayload;
+use crate::pdns::dns_name::{display_name, DnsName};
+use crate::pdns::struct_type::StructType;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Zone {
+    id: String,
+    name: String,
+    #[serde(rename = "type")]
+    type_id: Option<StructType>,
+    url: String,
+    kind: ZoneKind,
+    rrsets: Vec<Rrset>,
+    serial: u64,
+    edited_serial: u64,
+    masters: Vec<String>,
+    dnssec: bool,
+    nsec3param: String,
+    nsec3narrow: bool,
+    presigned: Option<bool>,
+    soa_edit: String,
+    soa_edit_api: String,
+    api_rectify: bool,
+    zone: Option<String>,
+    account: Option<String>,
+    catalog: Option<String>,
+    nameservers: Option<Vec<String>>,
+    master_tsig_key_ids: Vec<String>,
+    slave_tsig_key_ids: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct NewZone {
+    name: String,
+    #[serde(rename = "type")]
+    type_id: StructType,
+    kind: ZoneKind,
+    rrsets: Vec<Rrset>,
+    masters: Vec<String>,
+    dnssec: bool,
+    nsec3param: Option<String>,
+    nsec3narrow: bool,
+    presigned: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    soa_edit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    soa_edit_api: Option<String>,
+    api_rectify: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    catalog: Option<String>,
+    nameservers: Vec<String>,
+    master_tsig_key_ids: Option<Vec<String>>,
+    slave_tsig_key_ids: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ZoneUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kind: Option<ZoneKind>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    masters: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    soa_edit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    soa_edit_api: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    api_rectify: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dnssec: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nsec3param: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    catalog: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ZoneActionResult {
+    result: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ListZone {
+    id: String,
+    name: String,
+    url: String,
+    kind: ZoneKind,
+    serial: u64,
+    edited_serial: u64,
+    notified_serial: u64,
+    last_check: u64,
+    masters: Vec<String>,
+    dnssec: bool,
+    account: Option<String>,
+    catalog: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ZoneKind {
+    Native,
+    Master,
+    Slave,
+    Producer,
+    Consumer,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Rrset {
+    name: String,
+    #[serde(rename = "type")]
+    type_id: RrsetType,
+    ttl: Option<u32>,
+    changetype: Option<Changetype>,
+    records: Vec<Record>,
+    comments: Vec<Comment>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Rrsets {
+    rrsets: Vec<Rrset>,
+}
+
+/// Record type of an rrset, types without a variant of their own are kept by name, so zones with any
+/// record type can be read and written back
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum RrsetType {
+    A,
+    Ptr,
+    Aaaa,
+    Ns,
+    Soa,
+    Cname,
+    Srv,
+    Txt,
+    Lua,
+    Mx,
+    Caa,
+    Ds,
+    Dname,
+    Naptr,
+    Sshfp,
+    Tlsa,
+    Svcb,
+    Https,
+    Other(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Changetype {
+    None,
+    #[serde(rename = "REPLACE")]
+    Replace,
+    #[serde(rename = "DELETE")]
+    Delete,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Record {
+    content: String,
+    disabled: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Comment {
+    content: String,
+    account: String,
+    modified_at: u64,
+}
+
+impl PowerDnsPayload for Zone {}
+
+#[allow(dead_code)]
+impl Zone {
+    pub fn id(&self) -> String {
+        self.id.clone()
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn kind(&self) -> ZoneKind {
+        self.kind.clone()
+    }
+
+    pub fn rrsets(&self) -> Vec<Rrset> {
+        self.rrsets.clone()
+    }
+
+    /// Catalog the zone is a member of, servers without catalog support report none
+    pub fn catalog(&self) -> Option<String> {
+        self.catalog.clone().filter(|catalog| !catalog.is_empty())
+    }
+
+    pub fn soa_edit_api(&self) -> String {
+        self.soa_edit_api.clone()
+    }
+
+    pub fn serial(&self) -> u64 {
+        self.serial
+    }
+
+    pub fn masters(&self) -> Vec<String> {
+        self.masters.clone()
+    }
+
+    pub fn dnssec(&self) -> bool {
+        self.dnssec
+    }
+
+    pub fn nsec3param(&self) -> String {
+        self.nsec3param.clone()
+    }
+
+    pub fn nsec3narrow(&self) -> bool {
+        self.nsec3narrow
+    }
+
+    pub fn presigned(&self) -> bool {
+        self.presigned.unwrap_or(false)
+    }
+
+    pub fn soa_edit(&self) -> String {
+        self.soa_edit.clone()
+    }
+
+    pub fn api_rectify(&self) -> bool {
+        self.api_rectify
+    }
+
+    pub fn account(&self) -> Option<String> {
+        self.account.clone().filter(|account| !account.is_empty())
+    }
+
+    pub fn master_tsig_key_ids(&self) -> Vec<String> {
+        self.master_tsig_key_ids.clone()
+    }
+
+    pub fn slave_tsig_key_ids(&self) -> Vec<String> {
+        self.slave_tsig_key_ids.clone()
+    }
+}
+
+impl NewZone {
+    /// Zone of the kind, secondary zones keep only the masters, other zones only the records and
+    /// nameservers. Further settings are added with the `with_` methods.
+    pub fn new(name: &String, kind: ZoneKind, rrsets: &[Rrset], masters: &[String],
+               nameservers: &[String]) -> NewZone {
+        let secondary = kind.is_secondary();
+
+        NewZone {
+            name: DnsName::absolute(name).to_string(),
+            type_id: StructType::Zone,
+            kind,
+            rrsets: if secondary { Vec::new() } else { rrsets.to_vec() },
+            masters: if secondary { masters.to_vec() } else { Vec::new() },
+            dnssec: false,
+            nsec3param: None,
+            nsec3narrow: false,
+            presigned: false,
+            soa_edit: None,
+            soa_edit_api: None,
+            api_rectify: false,
+            account: None,
+            catalog: None,
+            nameservers: if secondary { Vec::new() } else { nameservers.to_vec() },
+            master_tsig_key_ids: None,
+            slave_tsig_key_ids: None,
+        }
+    }
+
+    pub fn with_dnssec(mut self, dnssec: bool, nsec3param: Option<String>, nsec3narrow: bool) -> NewZone {
+        self.dnssec = dnssec;
+        self.nsec3param = nsec3param;
+        self.nsec3narrow = nsec3narrow;
+        self
+    }
+
+    pub fn with_presigned(mut self, presigned: bool) -> NewZone {
+        self.presigned = presigned;
+        self
+    }
+
+    pub fn with_soa_edit(mut self, soa_edit: Option<String>, soa_edit_api: Option<String>) -> NewZone {
+        self.soa_edit = soa_edit;
+        self.soa_edit_api = soa_edit_api;
+        self
+    }
+
+    pub fn with_api_rectify(mut self, api_rectify: bool) -> NewZone {
+        self.api_rectify = api_rectify;
+        self
+    }
+
+    pub fn with_account(mut self, account: Option<String>) -> NewZone {
+        self.account = account;
+        self
+    }
+
+    pub fn with_catalog(mut self, catalog: Option<String>) -> NewZone {
+        self.catalog = catalog;
+        self
+    }
+
+    pub fn with_tsig_key_ids(mut self, master_tsig_key_ids: Option<Vec<String>>,
+                             slave_tsig_key_ids: Option<Vec<String>>) -> NewZone {
+        self.master_tsig_key_ids = master_tsig_key_ids;
+        self.slave_tsig_key_ids = slave_tsig_key_ids;
+        self
+    }
+}
+
+impl ZoneUpdate {
+    /// Update without changes, the changed settings are added with the `with_` methods
+    pub fn new() -> ZoneUpdate {
+        ZoneUpdate::default()
+    }
+
+    pub fn with_kind(mut self, kind: Option<ZoneKind>) -> ZoneUpdate {
+        self.kind = kind;
+        self
+    }
+
+    pub fn with_masters(mut self, masters: Option<Vec<String>>) -> ZoneUpdate {
+        self.masters = masters;
+        self
+    }
+
+    pub fn with_account(mut self, account: Option<String>) -> ZoneUpdate {
+        self.account = account;
+        self
+    }
+
+    pub fn with_soa_edit(mut self, soa_edit: Option<String>, soa_edit_api: Option<String>) -> ZoneUpdate {
+        self.soa_edit = soa_edit;
+        self.soa_edit_api = soa_edit_api;
+        self
+    }
+
+    pub fn with_api_rectify(mut self, api_rectify: Option<bool>) -> ZoneUpdate {
+        self.api_rectify = api_rectify;
+        self
+    }
+
+    pub fn with_dnssec(mut self, dnssec: Option<bool>, nsec3param: Option<String>) -> ZoneUpdate {
+        self.dnssec = dnssec;
+        self.nsec3param = nsec3param;
+        self
+    }
+
+    pub fn with_catalog(mut self, catalog: Option<String>) -> ZoneUpdate {
+        self.catalog = catalog;
+        self
+    }
+}
+
+#[allow(dead_code)]
+impl ListZone {
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn kind(&self) -> ZoneKind {
+        self.kind.clone()
+    }
+
+    pub fn serial(&self) -> u64 {
+        self.serial
+    }
+
+    pub fn catalog(&self) -> Option<String> {
+        self.catalog.clone().filter(|catalog| !catalog.is_empty())
+    }
+}
+
+impl ZoneActionResult {
+    pub fn result(&self) -> String {
+        self.result.clone()
+    }
+}
+
+impl ZoneKind {
+    /// Secondary zones receive their content from the zone masters by zone transfer
+    pub fn is_secondary(&self) -> bool {
+        match self {
+            ZoneKind::Slave | ZoneKind::Consumer => true,
+            _ => false,
+        }
+    }
+
+    /// Producer and consumer zones list the member zones of a catalog
+    pub fn is_catalog(&self) -> bool {
+        match self {
+            ZoneKind::Producer | ZoneKind::Consumer => true,
+            _ => false,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl Rrset {
+    pub fn new(name: &String,
+               type_id: RrsetType,
+               changetype: &Option<Changetype>,
+               ttl: &Option<u32>,
+               records: &Vec<Record>,
+               comments: &Vec<Comment>) -> Rrset {
+        Rrset {
+            name: name.clone(),
+            type_id: type_id,
+            changetype: changetype.clone(),
+            ttl: ttl.clone(),
+            records: records.clone(),
+            comments: comments.clone(),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn type_id(&self) -> RrsetType {
+        self.type_id.clone()
+    }
+
+    pub fn ttl(&self) -> Option<u32> {
+        self.ttl
+    }
+
+    pub fn records(&self) -> Vec<Record> {
+        self.records.clone()
+    }
+}
+
+impl Rrsets {
+    pub fn new(rrsets: &Vec<Rrset>) -> Rrsets {
+        Rrsets {
+            rrsets: rrsets.clone(),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl Comment {
+    pub fn new(content: &String, account: &String) -> Comment {
+        let stamp = match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(value) => value.as_secs(),
+            Err(_) => 0 as u64
+        };
+
+        Comment {
+            content: content.clone(),
+            account: account.clone(),
+            modified_at: stamp,
+        }
+    }
+}
+
+impl Record {
+    pub fn new(content: &String, disabled: bool) -> Record {
+        Record {
+            content: content.clone(),
+            disabled,
+        }
+    }
+
+    pub fn content(&self) -> String {
+        self.content.clone()
+    }
+
+    pub fn disabled(&self) -> bool {
+        self.disabled
+    }
+}
+
+impl Display for Zone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut rrsets: Vec<String> = Vec::new();
+
+        for rrset in &self.rrsets {
+            rrsets.push(format!("{}", rrset));
+        }
+
+        write!(f, "(id: {}, name: {}, type: {}, kind: {}, url: {}, rrsets: ({}), serial: {},  edited_serial: {}, masters: ({}), dnssec: {}, nsec3param: {}, nsec3narrow: {}, presigned: {}, soa_edit: {}, soa_edit_api: {}, api_rectify: {}, zone: {}, account: {}, catalog: {}, nameservers: ({}), master_tsig_key_ids: {}, slave_tsig_key_ids: {})",
+               &self.id, display_name(&self.name), &self.type_id.clone().unwrap_or(StructType::None),
+               &self.kind, &self.url,
+               rrsets.join(", "), self.serial, self.edited_serial,
+               &self.masters.join(", "), self.dnssec, &self.nsec3param, self.nsec3narrow,
+               self.presigned.clone().unwrap_or(false), &self.soa_edit, &self.soa_edit_api,
+               self.api_rectify,
+               &self.zone.clone().unwrap_or(String::new()),
+               &self.account.clone().unwrap_or(String::new()),
+               &self.catalog.clone().unwrap_or(String::new()),
+               &self.nameservers.clone().unwrap_or(Vec::new()).join(", "),
+               &self.master_tsig_key_ids.join(", "),
+               &self.slave_tsig_key_ids.join(", "))
+    }
+}
+
+impl Display for NewZone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut rrsets: Vec<String> = Vec::new();
+
+        for rrset in &self.rrsets {
+            rrsets.push(format!("{}", rrset));
+        }
+
+        write!(f, "(name: {}, type: {}, kind: {}, rrsets: ({}), masters: ({}), dnssec: {}, nsec3param: {}, nsec3narrow: {}, presigned: {}, soa_edit: {}, soa_edit_api: {}, api_rectify: {}, account: {}, catalog: {}, nameservers: ({}), master_tsig_key_ids: {}, slave_tsig_key_ids: {})",
+               display_name(&self.name), &self.type_id, &self.kind, rrsets.join(", "),
+               &self.masters.join(", "), self.dnssec,
+               &self.nsec3param.clone().unwrap_or(String::new()), self.nsec3narrow,
+               self.presigned,
+               &self.soa_edit.clone().unwrap_or(String::new()),
+               &self.soa_edit_api.clone().unwrap_or(String::new()),
+               self.api_rectify,
+               &self.account.clone().unwrap_or(String::new()),
+               &self.catalog.clone().unwrap_or(String::new()),
+               &self.nameservers.join(", "),
+               &self.master_tsig_key_ids.clone().unwrap_or(Vec::new()).join(", "),
+               &self.slave_tsig_key_ids.clone().unwrap_or(Vec::new()).join(", "))
+    }
+}
+
+impl Display for ZoneUpdate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(kind: {}, masters: ({}), account: {}, soa_edit: {}, soa_edit_api: {}, api_rectify: {}, dnssec: {}, nsec3param: {}, catalog: {})",
+               &self.kind.clone().map(|kind| kind.to_string()).unwrap_or(String::new()),
+               &self.masters.clone().unwrap_or(Vec::new()).join(", "),
+               &self.account.clone().unwrap_or(String::new()),
+               &self.soa_edit.clone().unwrap_or(String::new()),
+               &self.soa_edit_api.clone().unwrap_or(String::new()),
+               &self.api_rectify.map(|value| value.to_string()).unwrap_or(String::new()),
+               &self.dnssec.map(|value| value.to_string()).unwrap_or(String::new()),
+               &self.nsec3param.clone().unwrap_or(String::new()),
+               &self.catalog.clone().unwrap_or(String::new()))
+    }
+}
+
+impl Display for ZoneActionResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(result: {})", &self.result)
+    }
+}
+
+impl Display for ZoneKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ZoneKind::Master => write!(f, "Master"),
+            ZoneKind::Native => write!(f, "Native"),
+            ZoneKind::Slave => write!(f, "Slave"),
+            ZoneKind::Producer => write!(f, "Producer"),
+            ZoneKind::Consumer => write!(f, "Consumer"),
+        }
+    }
+}
+
+impl Display for Rrset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(name: {}, type: {}, changetype: {})",
+               display_name(&self.name), &self.type_id,
+               &self.changetype.clone().unwrap_or(Changetype::None))
+    }
+}
+
+impl Display for RrsetType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RrsetType::A => write!(f, "A"),
+            RrsetType::Aaaa => write!(f, "AAAA"),
+            RrsetType::Cname => write!(f, "CNAME"),
+            RrsetType::Ns => write!(f, "NS"),
+            RrsetType::Ptr => write!(f, "PTR"),
+            RrsetType::Soa => write!(f, "SOA"),
+            RrsetType::Srv => write!(f, "SRV"),
+            RrsetType::Txt => write!(f, "TXT"),
+            RrsetType::Lua => write!(f, "LUA"),
+            RrsetType::Mx => write!(f, "MX"),
+            RrsetType::Caa => write!(f, "CAA"),
+            RrsetType::Ds => write!(f, "DS"),
+            RrsetType::Dname => write!(f, "DNAME"),
+            RrsetType::Naptr => write!(f, "NAPTR"),
+            RrsetType::Sshfp => write!(f, "SSHFP"),
+            RrsetType::Tlsa => write!(f, "TLSA"),
+            RrsetType::Svcb => write!(f, "SVCB"),
+            RrsetType::Https => write!(f, "HTTPS"),
+            RrsetType::Other(type_id) => write!(f, "{}", type_id),
+        }
+    }
+}
+
+impl From<String> for RrsetType {
+    fn from(type_id: String) -> Self {
+        match type_id.as_str() {
+            "A" => RrsetType::A,
+            "PTR" => RrsetType::Ptr,
+            "AAAA" => RrsetType::Aaaa,
+            "NS" => RrsetType::Ns,
+            "SOA" => RrsetType::Soa,
+            "CNAME" => RrsetType::Cname,
+            "SRV" => RrsetType::Srv,
+            "TXT" => RrsetType::Txt,
+            "LUA" => RrsetType::Lua,
+            "MX" => RrsetType::Mx,
+            "CAA" => RrsetType::Caa,
+            "DS" => RrsetType::Ds,
+            "DNAME" => RrsetType::Dname,
+            "NAPTR" => RrsetType::Naptr,
+            "SSHFP" => RrsetType::Sshfp,
+            "TLSA" => RrsetType::Tlsa,
+            "SVCB" => RrsetType::Svcb,
+            "HTTPS" => RrsetType::Https,
+            _ => RrsetType::Other(type_id),
+        }
+    }
+}
+
+impl From<RrsetType> for String {
+    fn from(type_id: RrsetType) -> Self {
+        type_id.to_string()
+    }
+}
+
+impl Display for Changetype {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Changetype::Replace => write!(f, "REPLACE"),
+            Changetype::Delete => write!(f, "DELETE"),
+            Changetype::None => write!(f, "none"),
+        }
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(content: {}, disabled: {})", &self.content, self.disabled)
+    }
+}
+
+impl Display for Comment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(content: {}, account: {}, modified_at {})",
+               &self.content, &self.account, self.modified_at)
+    }
+}
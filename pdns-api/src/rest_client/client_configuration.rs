@@ -0,0 +1,260 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{Certificate, Client, Identity, Proxy, Url};
+
+use crate::pdns::reverse::parse_network_prefix;
+use crate::rest_client::errors::RestClientError;
+use crate::rest_client::http_trace::HttpTrace;
+use crate::rest_client::retry_policy::RetryPolicy;
+
+/// Version of the PowerDNS ReST API, resource paths are relative to "api/<version>/"
+pub const DEFAULT_API_VERSION: &'static str = "v1";
+
+/// TLS settings: PEM encoded CA bundle and client identity (certificate chain followed by the private key)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TlsConfiguration {
+    ca_cert: Option<Vec<u8>>,
+    client_identity: Option<Vec<u8>>,
+    insecure_skip_verify: bool,
+}
+
+/// Transport settings of the HTTP client talking to the PowerDNS API. The configured client
+/// is built once and shared by all requests.
+#[derive(Debug, Clone)]
+pub struct ClientConfiguration {
+    retry_policy: RetryPolicy,
+    connect_timeout: Duration,
+    request_timeout: Duration,
+    tls_configuration: TlsConfiguration,
+    proxy: Option<String>,
+    api_version: String,
+    http_trace: Option<Arc<HttpTrace>>,
+    client: Client,
+}
+
+/// Hosts bypassing an explicitly configured proxy, read from NO_PROXY like curl does:
+/// '*', domain names matching themselves and their subdomains, addresses and network prefixes
+#[derive(Debug, Clone, Default, PartialEq)]
+struct NoProxy {
+    matches_all: bool,
+    domains: Vec<String>,
+    addresses: Vec<IpAddr>,
+    networks: Vec<(IpAddr, u8)>,
+}
+
+impl TlsConfiguration {
+    pub fn new(ca_cert: &Option<Vec<u8>>, client_identity: &Option<Vec<u8>>, insecure_skip_verify: bool) -> TlsConfiguration {
+        TlsConfiguration {
+            ca_cert: ca_cert.clone(),
+            client_identity: client_identity.clone(),
+            insecure_skip_verify,
+        }
+    }
+}
+
+impl ClientConfiguration {
+    /// Without an explicit proxy the proxies from HTTP_PROXY and HTTPS_PROXY are used, an explicit
+    /// proxy is bypassed for the hosts listed in NO_PROXY
+    pub fn new(retry_policy: &RetryPolicy, connect_timeout: Duration, request_timeout: Duration,
+               tls_configuration: &TlsConfiguration,
+               proxy: &Option<String>, api_version: &String,
+               http_trace: Option<HttpTrace>) -> Result<ClientConfiguration, RestClientError> {
+        let mut builder = Client::builder()
+            .connect_timeout(connect_timeout)
+            .timeout(request_timeout);
+
+        if let Some(ca_cert) = &tls_configuration.ca_cert {
+            builder = builder.add_root_certificate(Certificate::from_pem(ca_cert)
+                .map_err(|error| RestClientError::on_reqwest_runtime_error(error.to_string()))?);
+        }
+        if let Some(client_identity) = &tls_configuration.client_identity {
+            builder = builder
+                .use_rustls_tls()
+                .identity(Identity::from_pem(client_identity)
+                    .map_err(|error| RestClientError::on_reqwest_runtime_error(error.to_string()))?);
+        }
+        if tls_configuration.insecure_skip_verify {
+            builder = builder.danger_accept_invalid_certs(true);
+        }
+        if let Some(proxy) = proxy {
+            let proxy_url = Url::parse(proxy)
+                .map_err(|error| RestClientError::on_reqwest_runtime_error(error.to_string()))?;
+            let no_proxy = NoProxy::from_env();
+
+            builder = builder.proxy(Proxy::custom(move |url| match url.host_str() {
+                Some(host) if no_proxy.contains(host) => None,
+                _ => Some(proxy_url.clone()),
+            }));
+        }
+
+        Ok(ClientConfiguration {
+            retry_policy: retry_policy.clone(),
+            connect_timeout,
+            request_timeout,
+            tls_configuration: tls_configuration.clone(),
+            proxy: proxy.clone(),
+            api_version: api_version.clone(),
+            http_trace: http_trace.map(Arc::new),
+            client: builder
+                .build()
+                .map_err(|error| RestClientError::on_reqwest_runtime_error(error.to_string()))?,
+        })
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    pub fn connect_timeout(&self) -> Duration {
+        self.connect_timeout
+    }
+
+    pub fn request_timeout(&self) -> Duration {
+        self.request_timeout
+    }
+
+    pub fn proxy(&self) -> Option<String> {
+        self.proxy.clone()
+    }
+
+    pub fn api_version(&self) -> String {
+        self.api_version.clone()
+    }
+
+    pub fn http_trace(&self) -> Option<Arc<HttpTrace>> {
+        self.http_trace.clone()
+    }
+
+    pub fn client(&self) -> Client {
+        self.client.clone()
+    }
+}
+
+impl NoProxy {
+    fn from_env() -> NoProxy {
+        NoProxy::parse(&env::var("NO_PROXY")
+            .or_else(|_| env::var("no_proxy"))
+            .unwrap_or_default())
+    }
+
+    fn parse(value: &str) -> NoProxy {
+        let mut no_proxy = NoProxy::default();
+
+        for entry in value.split(',').map(|entry| entry.trim()).filter(|entry| !entry.is_empty()) {
+            if entry == "*" {
+                no_proxy.matches_all = true;
+            } else if let Some(network) = parse_network_prefix(entry) {
+                no_proxy.networks.push(network);
+            } else if let Ok(address) = IpAddr::from_str(entry) {
+                no_proxy.addresses.push(address);
+            } else {
+                no_proxy.domains.push(entry.trim_start_matches('.').to_lowercase());
+            }
+        }
+
+        no_proxy
+    }
+
+    fn contains(&self, host: &str) -> bool {
+        let host = host.trim_start_matches('[').trim_end_matches(']').to_lowercase();
+
+        match IpAddr::from_str(host.as_str()) {
+            _ if self.matches_all => true,
+            Ok(address) => self.addresses.contains(&address)
+                || self.networks.iter().any(|(network, prefix_len)| in_network(&address, network, *prefix_len)),
+            Err(_) => self.domains.iter()
+                .any(|domain| host == *domain || host.ends_with(format!(".{}", domain).as_str())),
+        }
+    }
+}
+
+fn in_network(address: &IpAddr, network: &IpAddr, prefix_len: u8) -> bool {
+    match (address, network) {
+        (IpAddr::V4(address), IpAddr::V4(network)) => {
+            let shift = 32 - prefix_len as u32;
+
+            u32::from(*address).checked_shr(shift).unwrap_or(0) == u32::from(*network).checked_shr(shift).unwrap_or(0)
+        }
+        (IpAddr::V6(address), IpAddr::V6(network)) => {
+            let shift = 128 - prefix_len as u32;
+
+            u128::from(*address).checked_shr(shift).unwrap_or(0) == u128::from(*network).checked_shr(shift).unwrap_or(0)
+        }
+        _ => false,
+    }
+}
+
+impl Default for ClientConfiguration {
+    fn default() -> Self {
+        ClientConfiguration::new(&RetryPolicy::default(), Duration::from_secs(10), Duration::from_secs(30),
+                                 &TlsConfiguration::default(), &None,
+                                 &DEFAULT_API_VERSION.to_string(), None).unwrap()
+    }
+}
+
+impl Display for TlsConfiguration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(ca_cert: {}, client_identity: {}, insecure_skip_verify: {})",
+               self.ca_cert.is_some(), self.client_identity.is_some(), self.insecure_skip_verify)
+    }
+}
+
+impl Display for ClientConfiguration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(retry_policy: {}, connect_timeout: {} s, request_timeout: {} s, tls: {}, proxy: {}, api_version: {}, http_trace: {})",
+               &self.retry_policy, self.connect_timeout.as_secs(), self.request_timeout.as_secs(),
+               &self.tls_configuration, &self.proxy.clone().unwrap_or("None".to_string()), &self.api_version,
+               &self.http_trace.as_ref().map(|http_trace| http_trace.to_string()).unwrap_or("None".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest_client::client_configuration::NoProxy;
+
+    #[test]
+    fn should_bypass_proxy_for_domains_and_subdomains() {
+        let no_proxy = NoProxy::parse("example.com, .internal.example.org");
+
+        assert_eq!(no_proxy.contains("example.com"), true);
+        assert_eq!(no_proxy.contains("pdns.EXAMPLE.com"), true);
+        assert_eq!(no_proxy.contains("pdns.internal.example.org"), true);
+        assert_eq!(no_proxy.contains("notexample.com"), false);
+        assert_eq!(no_proxy.contains("example.org"), false)
+    }
+
+    #[test]
+    fn should_bypass_proxy_for_addresses_and_networks() {
+        let no_proxy = NoProxy::parse("127.0.0.1,10.0.0.0/8,2001:db8::/32");
+
+        assert_eq!(no_proxy.contains("127.0.0.1"), true);
+        assert_eq!(no_proxy.contains("10.1.2.3"), true);
+        assert_eq!(no_proxy.contains("[2001:db8::53]"), true);
+        assert_eq!(no_proxy.contains("11.0.0.1"), false);
+        assert_eq!(no_proxy.contains("[2001:db9::53]"), false)
+    }
+
+    #[test]
+    fn should_bypass_proxy_for_all_hosts_on_wildcard() {
+        assert_eq!(NoProxy::parse("*").contains("pdns.example.com"), true);
+        assert_eq!(NoProxy::parse("").contains("pdns.example.com"), false)
+    }
+}
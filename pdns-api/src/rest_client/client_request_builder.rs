@@ -0,0 +1,249 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::Utc;
+use log::warn;
+use reqwest::header::{HeaderName, HeaderValue, ACCEPT, HeaderMap, CONTENT_TYPE, CACHE_CONTROL};
+use reqwest::{Client, Request, RequestBuilder, Response, Url};
+
+use crate::rest_client::client_configuration::ClientConfiguration;
+use crate::rest_client::errors::RestClientError;
+use crate::rest_client::http_trace::{HttpExchange, HttpTrace, HttpTraceRequest, HttpTraceResponse};
+use crate::rest_client::retry_policy::RetryPolicy;
+use crate::rest_client::transport::{Transport, TransportError, UNIX_SOCKET_SCHEME};
+
+/// Base URI of requests sent over a Unix socket, the host only ends up in the Host header
+const UNIX_SOCKET_BASE_URI: &'static str = "http://localhost/";
+
+pub struct ClientRequestBuilder {
+    base_uri: String,
+    api_version: String,
+    api_key: String,
+    client: Client,
+    retry_policy: RetryPolicy,
+    transport: Transport,
+    http_trace: Option<Arc<HttpTrace>>,
+}
+
+impl ClientRequestBuilder {
+    /// A base URI like 'unix:///run/pdns/api.sock' selects the Unix socket transport. Any other base URI
+    /// may carry a path prefix, it is treated as a directory whether or not it ends with a slash.
+    pub fn new(base_uri: &String, api_key: &String, client_configuration: &ClientConfiguration) -> ClientRequestBuilder {
+        let (mut base_uri, transport) = match Url::parse(base_uri) {
+            Ok(url) if url.scheme() == UNIX_SOCKET_SCHEME => (UNIX_SOCKET_BASE_URI.to_string(),
+                                                  Transport::unix_socket(Path::new(url.path()),
+                                                                         client_configuration.connect_timeout(),
+                                                                         client_configuration.request_timeout())),
+            _ => (base_uri.clone(), Transport::Http { client: client_configuration.client() }),
+        };
+
+        if !base_uri.ends_with("/") {
+            base_uri.push('/');
+        }
+
+        ClientRequestBuilder {
+            base_uri,
+            api_version: client_configuration.api_version(),
+            api_key: api_key.clone(),
+            client: client_configuration.client(),
+            retry_policy: client_configuration.retry_policy().clone(),
+            transport,
+            http_trace: client_configuration.http_trace(),
+        }
+    }
+
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Resolve a resource path like 'servers/localhost' against the API root '<base URI>/api/<version>/'
+    pub fn request_url(&self, path: &str) -> Result<Url, RestClientError> {
+        Url::parse(self.base_uri.as_str())
+            .and_then(|base_url| base_url.join(format!("api/{}/", self.api_version).as_str()))
+            .and_then(|api_url| api_url.join(path.trim_start_matches('/')))
+            .map_err(|error| RestClientError::on_unspecified_error_message(
+                &format!("Malformed request URI for {}{}: {}", &self.base_uri, path, error)))
+    }
+
+    pub async fn send(&self, request: Request, attempt: u32) -> Result<Response, TransportError> {
+        match &self.http_trace {
+            Some(http_trace) => self.send_traced(http_trace, request, attempt).await,
+            None => self.transport.send(request).await,
+        }
+    }
+
+    /// Send the request and record the exchange. The response body is buffered for the trace
+    /// and handed on in a rebuilt response.
+    async fn send_traced(&self, http_trace: &HttpTrace, request: Request, attempt: u32) -> Result<Response, TransportError> {
+        let request_body = request.body()
+            .and_then(|body| body.as_bytes())
+            .map(|bytes| String::from_utf8_lossy(bytes).to_string());
+        let traced_request = HttpTraceRequest::new(request.method(), request.url(), request.version(),
+                                                   request.headers(), &request_body);
+        let started = Utc::now();
+        let timer = Instant::now();
+
+        let (result, traced_response) = match self.transport.send(request).await {
+            Ok(response) => {
+                let status = response.status();
+                let response_version = response.version();
+                let response_headers = response.headers().clone();
+
+                match response.bytes().await {
+                    Ok(body) => {
+                        let traced_response = HttpTraceResponse::new(status, response_version, &response_headers,
+                                                                     &String::from_utf8_lossy(&body));
+                        let mut builder = hyper::Response::builder().status(status).version(response_version);
+
+                        if let Some(headers) = builder.headers_mut() {
+                            *headers = response_headers;
+                        }
+
+                        (builder.body(body.to_vec())
+                             .map(Response::from)
+                             .map_err(|error| TransportError::Other { message: error.to_string() }),
+                         Ok(traced_response))
+                    }
+                    Err(error) => {
+                        let message = error.to_string();
+
+                        (Err(TransportError::from(error)), Err(message))
+                    }
+                }
+            }
+            Err(error) => (Err(error.clone()), Err(error.to_string())),
+        };
+
+        let exchange = HttpExchange::new(&started, timer.elapsed(), attempt, &traced_request, &traced_response);
+
+        if let Err(error) = http_trace.record(&exchange) {
+            warn!("Failed to record HTTP trace: {}", error);
+        }
+
+        result
+    }
+
+    pub fn get_for_path(&self, path: &str) -> Result<RequestBuilder, RestClientError> {
+        let request_url = self.request_url(path)?;
+        let mut headers = HeaderMap::new();
+
+        headers.append(HeaderName::from_static("x-api-key"),
+                       HeaderValue::from_str(&self.api_key.clone().as_str()).unwrap());
+        headers.append(ACCEPT, HeaderValue::from_static("application/json"));
+        headers.append(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+
+        Ok(self.client.get(request_url).headers(headers))
+    }
+
+    pub fn get_text_for_path(&self, path: &str) -> Result<RequestBuilder, RestClientError> {
+        let request_url = self.request_url(path)?;
+        let mut headers = HeaderMap::new();
+
+        headers.append(HeaderName::from_static("x-api-key"),
+                       HeaderValue::from_str(&self.api_key.clone().as_str()).unwrap());
+        headers.append(ACCEPT, HeaderValue::from_static("text/plain"));
+        headers.append(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+
+        Ok(self.client.get(request_url).headers(headers))
+    }
+
+    pub fn post_for_path(&self, path: &str) -> Result<RequestBuilder, RestClientError> {
+        let request_url = self.request_url(path)?;
+        let mut headers = HeaderMap::new();
+
+        headers.append(HeaderName::from_static("x-api-key"),
+                       HeaderValue::from_str(&self.api_key.clone().as_str()).unwrap());
+        headers.append(ACCEPT, HeaderValue::from_static("application/json"));
+        headers.append(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.append(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+
+        Ok(self.client.post(request_url).headers(headers))
+    }
+
+    pub fn delete_for_path(&self, path: &str) -> Result<RequestBuilder, RestClientError> {
+        let request_url = self.request_url(path)?;
+        let mut headers = HeaderMap::new();
+
+        headers.append(HeaderName::from_static("x-api-key"),
+                       HeaderValue::from_str(&self.api_key.clone().as_str()).unwrap());
+        headers.append(ACCEPT, HeaderValue::from_static("application/json"));
+        headers.append(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+
+        Ok(self.client.delete(request_url).headers(headers))
+    }
+
+    pub fn patch_for_path(&self, path: &str) -> Result<RequestBuilder, RestClientError> {
+        let request_url = self.request_url(path)?;
+        let mut headers = HeaderMap::new();
+
+        headers.append(HeaderName::from_static("x-api-key"),
+                       HeaderValue::from_str(&self.api_key.clone().as_str()).unwrap());
+        headers.append(ACCEPT, HeaderValue::from_static("application/json"));
+        headers.append(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.append(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+
+        Ok(self.client.patch(request_url).headers(headers))
+    }
+
+    pub fn put_for_path(&self, path: &str) -> Result<RequestBuilder, RestClientError> {
+        let request_url = self.request_url(path)?;
+        let mut headers = HeaderMap::new();
+
+        headers.append(HeaderName::from_static("x-api-key"),
+                       HeaderValue::from_str(&self.api_key.clone().as_str()).unwrap());
+        headers.append(ACCEPT, HeaderValue::from_static("application/json"));
+        headers.append(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.append(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+
+        Ok(self.client.put(request_url).headers(headers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest_client::client_configuration::ClientConfiguration;
+    use crate::rest_client::client_request_builder::ClientRequestBuilder;
+
+    fn request_url(base_uri: &str, path: &str) -> String {
+        ClientRequestBuilder::new(&base_uri.to_string(), &"secret".to_string(), &ClientConfiguration::default())
+            .request_url(path)
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn should_join_path_to_base_uri() {
+        assert_eq!(request_url("http://localhost:8081/", "servers/localhost"),
+                   "http://localhost:8081/api/v1/servers/localhost");
+        assert_eq!(request_url("http://localhost:8081", "servers/localhost"),
+                   "http://localhost:8081/api/v1/servers/localhost")
+    }
+
+    #[test]
+    fn should_keep_path_prefix_of_base_uri() {
+        assert_eq!(request_url("https://gw.internal/dns/pdns/", "servers/localhost/zones/example.com."),
+                   "https://gw.internal/dns/pdns/api/v1/servers/localhost/zones/example.com.");
+        assert_eq!(request_url("https://gw.internal/dns/pdns", "/servers/localhost"),
+                   "https://gw.internal/dns/pdns/api/v1/servers/localhost")
+    }
+
+    #[test]
+    fn should_send_unix_socket_requests_to_api_root() {
+        assert_eq!(request_url("unix:///run/pdns/api.sock", "servers/localhost"),
+                   "http://localhost/api/v1/servers/localhost")
+    }
+}
@@ -0,0 +1,240 @@
+
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+
+use reqwest::StatusCode;
+
+use crate::pdns::error::Error;
+use crate::pdns::server::DaemonType;
+
+/// Error returned by all client operations. Match on [`RestClientError::kind`] to tell the cause apart.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RestClientError {
+    pub(super) kind: RestClientErrorKind,
+}
+
+/// Cause of a [`RestClientError`]. New causes may be added in minor releases.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RestClientErrorKind {
+    UnspecifiedError {
+        message: Option<String>,
+    },
+    ReqwestRuntimeError {
+        reqwest_error: String,
+    },
+    ClientError {
+        status_code: StatusCode,
+    },
+    PowerDnsServerError {
+        status_code: StatusCode,
+        server_error: Error,
+    },
+    WrongDaemonType {
+        server: String,
+        version: String,
+        daemon_type: DaemonType,
+        expected: DaemonType,
+    },
+    UnsupportedServerVersion {
+        server: String,
+        version: String,
+        feature: String,
+        required_version: String,
+    },
+    FeatureNotEnabled {
+        server: String,
+        version: String,
+        feature: String,
+    },
+}
+
+impl RestClientError {
+    pub fn on_unspecified_error() -> RestClientError {
+        RestClientError {
+            kind: RestClientErrorKind::on_unspecified_error(),
+        }
+    }
+
+    pub fn on_unspecified_error_message(message: &String) -> RestClientError {
+        RestClientError {
+            kind: RestClientErrorKind::on_unspecified_error_message(message),
+        }
+    }
+
+    pub fn on_reqwest_runtime_error(reqwest_error: String) -> RestClientError {
+        RestClientError {
+            kind: RestClientErrorKind::on_reqwest_runtime_error(reqwest_error),
+        }
+    }
+
+    pub fn on_client_error(status_code: StatusCode) -> RestClientError {
+        RestClientError {
+            kind: RestClientErrorKind::on_client_error(status_code),
+        }
+    }
+
+    pub fn on_powerdns_server_error(status_code: StatusCode, server_error: Error) -> RestClientError {
+        RestClientError {
+            kind: RestClientErrorKind::on_powerdns_server_error(status_code, server_error),
+        }
+    }
+
+    pub fn on_wrong_daemon_type(server: &String, version: &String, daemon_type: DaemonType, expected: DaemonType) -> RestClientError {
+        RestClientError {
+            kind: RestClientErrorKind::on_wrong_daemon_type(server, version, daemon_type, expected),
+        }
+    }
+
+    pub fn on_unsupported_server_version(server: &String, version: &String, feature: &String, required_version: &String) -> RestClientError {
+        RestClientError {
+            kind: RestClientErrorKind::on_unsupported_server_version(server, version, feature, required_version),
+        }
+    }
+
+    pub fn on_feature_not_enabled(server: &String, version: &String, feature: &String) -> RestClientError {
+        RestClientError {
+            kind: RestClientErrorKind::on_feature_not_enabled(server, version, feature),
+        }
+    }
+
+    pub fn kind(&self) -> RestClientErrorKind {
+        self.kind.clone()
+    }
+
+    fn __description(&self) -> String {
+        match &self.kind {
+            RestClientErrorKind::UnspecifiedError {
+                message
+            }  => format!("Unspecified error: {}", &message.clone().unwrap_or("None".to_string())),
+            RestClientErrorKind::ReqwestRuntimeError {
+                reqwest_error
+            } => format!("Reqwest runtime error: {}", reqwest_error),
+            RestClientErrorKind::ClientError {
+                status_code
+            } => format!("Unexpected client status code {}", status_code),
+            RestClientErrorKind::PowerDnsServerError {
+                server_error,
+                status_code,
+            } => format!("PowerDNS server error: status code: {}, server error {}",
+                         status_code,
+                         server_error),
+            RestClientErrorKind::WrongDaemonType {
+                server,
+                version,
+                daemon_type,
+                expected,
+            } => format!("Server {} (version {}) is {}, expected {}", server, version, daemon_type, expected),
+            RestClientErrorKind::UnsupportedServerVersion {
+                server,
+                version,
+                feature,
+                required_version,
+            } => format!("Server {} (version {}) does not support {}, requires version {} or later",
+                         server, version, feature, required_version),
+            RestClientErrorKind::FeatureNotEnabled {
+                server,
+                version,
+                feature,
+            } => format!("Server {} (version {}) does not have {} enabled", server, version, feature),
+        }
+    }
+}
+
+impl fmt::Display for RestClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.__description().fmt(f)
+    }
+}
+
+impl std::error::Error for RestClientError {}
+
+impl RestClientErrorKind {
+    fn on_unspecified_error() -> RestClientErrorKind {
+        RestClientErrorKind::UnspecifiedError {
+            message: None,
+        }
+    }
+
+    fn on_unspecified_error_message(message: &String) -> RestClientErrorKind {
+        RestClientErrorKind::UnspecifiedError {
+            message: Some(message.clone()),
+        }
+    }
+
+    fn on_reqwest_runtime_error(reqwest_error: String) -> RestClientErrorKind {
+        RestClientErrorKind::ReqwestRuntimeError { reqwest_error }
+    }
+
+    fn on_client_error(status_code: StatusCode) -> RestClientErrorKind {
+        RestClientErrorKind::ClientError { status_code }
+    }
+
+    fn on_powerdns_server_error(status_code: StatusCode, server_error: Error) -> RestClientErrorKind {
+        RestClientErrorKind::PowerDnsServerError {
+            status_code,
+            server_error,
+        }
+    }
+
+    fn on_wrong_daemon_type(server: &String, version: &String, daemon_type: DaemonType, expected: DaemonType) -> RestClientErrorKind {
+        RestClientErrorKind::WrongDaemonType {
+            server: server.clone(),
+            version: version.clone(),
+            daemon_type,
+            expected,
+        }
+    }
+
+    fn on_unsupported_server_version(server: &String, version: &String, feature: &String, required_version: &String) -> RestClientErrorKind {
+        RestClientErrorKind::UnsupportedServerVersion {
+            server: server.clone(),
+            version: version.clone(),
+            feature: feature.clone(),
+            required_version: required_version.clone(),
+        }
+    }
+
+    fn on_feature_not_enabled(server: &String, version: &String, feature: &String) -> RestClientErrorKind {
+        RestClientErrorKind::FeatureNotEnabled {
+            server: server.clone(),
+            version: version.clone(),
+            feature: feature.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pdns::server::DaemonType;
+    use crate::rest_client::errors::RestClientError;
+
+    #[test]
+    fn should_name_server_version_and_expected_daemon_type() {
+        let error = RestClientError::on_wrong_daemon_type(&"http://ns1.example.com:8082".to_string(), &"4.9.0".to_string(),
+                                                          DaemonType::Recursor, DaemonType::Authoritative);
+
+        assert_eq!(error.to_string(),
+                   "Server http://ns1.example.com:8082 (version 4.9.0) is RECURSOR, expected AUTHORITATIVE")
+    }
+
+    #[test]
+    fn should_name_feature_not_enabled() {
+        let error = RestClientError::on_feature_not_enabled(&"http://ns1.example.com:8081".to_string(), &"4.9.0".to_string(),
+                                                            &"LUA records".to_string());
+
+        assert_eq!(error.to_string(), "Server http://ns1.example.com:8081 (version 4.9.0) does not have LUA records enabled")
+    }
+}
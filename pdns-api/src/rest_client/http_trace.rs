@@ -0,0 +1,409 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use log::warn;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode, Url, Version};
+use serde_json::{json, Value};
+
+/// Headers carrying credentials, their values never show up in a trace
+const REDACTED_HEADERS: [&'static str; 3] = ["x-api-key", "authorization", "proxy-authorization"];
+/// Body fields carrying secrets, like the private keys of cryptokeys
+const REDACTED_FIELDS: [&'static str; 1] = ["privatekey"];
+const REDACTED_VALUE: &'static str = "[REDACTED]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpTraceFormat {
+    JsonLines,
+    Har,
+}
+
+/// Records every request sent to the PowerDNS API together with its response or error.
+/// Without a trace file the exchanges are written to stderr as JSON lines. A HAR file is
+/// a single document, its entries are collected and written when the trace is dropped.
+#[derive(Debug)]
+pub struct HttpTrace {
+    format: HttpTraceFormat,
+    path: Option<PathBuf>,
+    har_entries: Mutex<Vec<Value>>,
+}
+
+/// A single request/response exchange, one per attempt
+#[derive(Debug, Clone)]
+pub struct HttpExchange {
+    started: DateTime<Utc>,
+    duration: Duration,
+    attempt: u32,
+    request: HttpTraceRequest,
+    response: Result<HttpTraceResponse, String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpTraceRequest {
+    method: Method,
+    url: Url,
+    version: Version,
+    headers: HeaderMap,
+    body: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpTraceResponse {
+    status: StatusCode,
+    version: Version,
+    headers: HeaderMap,
+    body: String,
+}
+
+impl HttpTraceFormat {
+    pub fn from_name(name: &str) -> Option<HttpTraceFormat> {
+        match name {
+            "jsonl" => Some(HttpTraceFormat::JsonLines),
+            "har" => Some(HttpTraceFormat::Har),
+            _ => None,
+        }
+    }
+}
+
+impl HttpTrace {
+    /// Create the trace, an existing trace file is truncated
+    pub fn new(format: HttpTraceFormat, path: &Option<PathBuf>) -> io::Result<HttpTrace> {
+        if let Some(path) = path {
+            File::create(path)?;
+        }
+
+        Ok(HttpTrace {
+            format,
+            path: path.clone(),
+            har_entries: Mutex::new(Vec::new()),
+        })
+    }
+
+    pub fn format(&self) -> HttpTraceFormat {
+        self.format
+    }
+
+    pub fn path(&self) -> Option<PathBuf> {
+        self.path.clone()
+    }
+
+    pub fn record(&self, exchange: &HttpExchange) -> io::Result<()> {
+        match (self.format, &self.path) {
+            (HttpTraceFormat::Har, Some(_)) => {
+                self.har_entries.lock().unwrap().push(exchange.har_entry());
+
+                Ok(())
+            }
+            (_, Some(path)) => {
+                let mut file = OpenOptions::new().append(true).create(true).open(path)?;
+
+                writeln!(file, "{}", exchange.json_line())
+            }
+            (_, None) => writeln!(io::stderr(), "{}", exchange.json_line()),
+        }
+    }
+}
+
+impl Drop for HttpTrace {
+    fn drop(&mut self) {
+        if let (HttpTraceFormat::Har, Some(path)) = (self.format, &self.path) {
+            let har = json!({
+                "log": {
+                    "version": "1.2",
+                    "creator": { "name": env!("CARGO_PKG_NAME"), "version": env!("CARGO_PKG_VERSION") },
+                    "entries": *self.har_entries.get_mut().unwrap(),
+                }
+            });
+
+            if let Err(error) = serde_json::to_string_pretty(&har)
+                .map_err(io::Error::from)
+                .and_then(|har| std::fs::write(path, har)) {
+                warn!("Failed to write HTTP trace to {}: {}", path.display(), error);
+            }
+        }
+    }
+}
+
+impl HttpExchange {
+    pub fn new(started: &DateTime<Utc>, duration: Duration, attempt: u32, request: &HttpTraceRequest,
+               response: &Result<HttpTraceResponse, String>) -> HttpExchange {
+        HttpExchange {
+            started: *started,
+            duration,
+            attempt,
+            request: request.clone(),
+            response: response.clone(),
+        }
+    }
+
+    fn json_line(&self) -> Value {
+        let (status, response_headers, response_body, error) = match &self.response {
+            Ok(response) => (Some(response.status.as_u16()), Some(headers_object(&response.headers)),
+                             Some(response.body.clone()), None),
+            Err(error) => (None, None, None, Some(error.clone())),
+        };
+
+        json!({
+            "started": self.started.to_rfc3339_opts(SecondsFormat::Millis, true),
+            "duration_ms": self.duration.as_millis() as u64,
+            "attempt": self.attempt,
+            "method": self.request.method.as_str(),
+            "url": self.request.url.as_str(),
+            "request_headers": headers_object(&self.request.headers),
+            "request_body": self.request.body,
+            "status": status,
+            "response_headers": response_headers,
+            "response_body": response_body,
+            "error": error,
+        })
+    }
+
+    /// Entry of the HAR 1.2 format, failed requests get the status 0 like browsers record them
+    fn har_entry(&self) -> Value {
+        let request_body_size = self.request.body.as_ref().map(|body| body.len() as i64).unwrap_or(0);
+        let mut request = json!({
+            "method": self.request.method.as_str(),
+            "url": self.request.url.as_str(),
+            "httpVersion": format!("{:?}", self.request.version),
+            "cookies": [],
+            "headers": headers_array(&self.request.headers),
+            "queryString": self.request.url.query_pairs()
+                .map(|(name, value)| json!({ "name": name, "value": value }))
+                .collect::<Vec<Value>>(),
+            "headersSize": -1,
+            "bodySize": request_body_size,
+        });
+
+        if let Some(body) = &self.request.body {
+            request["postData"] = json!({
+                "mimeType": header_value(&self.request.headers, "content-type"),
+                "text": body,
+            });
+        }
+
+        let response = match &self.response {
+            Ok(response) => json!({
+                "status": response.status.as_u16(),
+                "statusText": response.status.canonical_reason().unwrap_or(""),
+                "httpVersion": format!("{:?}", response.version),
+                "cookies": [],
+                "headers": headers_array(&response.headers),
+                "content": {
+                    "size": response.body.len(),
+                    "mimeType": header_value(&response.headers, "content-type"),
+                    "text": response.body,
+                },
+                "redirectURL": "",
+                "headersSize": -1,
+                "bodySize": response.body.len(),
+            }),
+            Err(error) => json!({
+                "status": 0,
+                "statusText": "",
+                "httpVersion": "",
+                "cookies": [],
+                "headers": [],
+                "content": { "size": 0, "mimeType": "" },
+                "redirectURL": "",
+                "headersSize": -1,
+                "bodySize": -1,
+                "_error": error,
+            }),
+        };
+
+        json!({
+            "startedDateTime": self.started.to_rfc3339_opts(SecondsFormat::Millis, true),
+            "time": self.duration.as_millis() as u64,
+            "request": request,
+            "response": response,
+            "cache": {},
+            "timings": { "send": 0, "wait": self.duration.as_millis() as u64, "receive": 0 },
+            "_attempt": self.attempt,
+        })
+    }
+}
+
+impl HttpTraceRequest {
+    pub fn new(method: &Method, url: &Url, version: Version, headers: &HeaderMap, body: &Option<String>) -> HttpTraceRequest {
+        HttpTraceRequest {
+            method: method.clone(),
+            url: url.clone(),
+            version,
+            headers: headers.clone(),
+            body: body.as_ref().map(|body| redacted_body(body)),
+        }
+    }
+}
+
+impl HttpTraceResponse {
+    pub fn new(status: StatusCode, version: Version, headers: &HeaderMap, body: &str) -> HttpTraceResponse {
+        HttpTraceResponse {
+            status,
+            version,
+            headers: headers.clone(),
+            body: redacted_body(body),
+        }
+    }
+}
+
+impl fmt::Display for HttpTraceFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HttpTraceFormat::JsonLines => write!(f, "jsonl"),
+            HttpTraceFormat::Har => write!(f, "har"),
+        }
+    }
+}
+
+impl fmt::Display for HttpTrace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "({} to {})", self.format, path.display()),
+            None => write!(f, "({} to stderr)", self.format),
+        }
+    }
+}
+
+fn redacted_value(name: &str, value: &[u8]) -> String {
+    match REDACTED_HEADERS.contains(&name.to_lowercase().as_str()) {
+        true => REDACTED_VALUE.to_string(),
+        false => String::from_utf8_lossy(value).to_string(),
+    }
+}
+
+/// JSON bodies with the secret fields replaced, other bodies are kept as they are
+fn redacted_body(body: &str) -> String {
+    fn redact(value: &mut Value) {
+        match value {
+            Value::Object(fields) => fields.iter_mut().for_each(|(name, value)| match REDACTED_FIELDS.contains(&name.as_str()) {
+                true => *value = Value::String(REDACTED_VALUE.to_string()),
+                false => redact(value),
+            }),
+            Value::Array(values) => values.iter_mut().for_each(redact),
+            _ => {}
+        }
+    }
+
+    match serde_json::from_str::<Value>(body) {
+        Ok(mut value) if REDACTED_FIELDS.iter().any(|field| body.contains(field)) => {
+            redact(&mut value);
+            value.to_string()
+        }
+        _ => body.to_string(),
+    }
+}
+
+fn header_value(headers: &HeaderMap, name: &str) -> String {
+    headers.get(name)
+        .map(|value| redacted_value(name, value.as_bytes()))
+        .unwrap_or_default()
+}
+
+fn headers_object(headers: &HeaderMap) -> Value {
+    Value::Object(headers.iter()
+        .map(|(name, value)| (name.to_string(), Value::String(redacted_value(name.as_str(), value.as_bytes()))))
+        .collect())
+}
+
+fn headers_array(headers: &HeaderMap) -> Value {
+    Value::Array(headers.iter()
+        .map(|(name, value)| json!({ "name": name.as_str(), "value": redacted_value(name.as_str(), value.as_bytes()) }))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::Utc;
+    use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT};
+    use reqwest::{Method, StatusCode, Url, Version};
+
+    use crate::rest_client::http_trace::{HttpExchange, HttpTrace, HttpTraceFormat, HttpTraceRequest, HttpTraceResponse};
+
+    fn exchange() -> HttpExchange {
+        let mut headers = HeaderMap::new();
+
+        headers.append(HeaderName::from_static("x-api-key"), HeaderValue::from_static("secret"));
+        headers.append(ACCEPT, HeaderValue::from_static("application/json"));
+
+        HttpExchange::new(&Utc::now(), Duration::from_millis(12), 1,
+                          &HttpTraceRequest::new(&Method::PATCH,
+                                                 &Url::parse("http://localhost:8081/api/v1/servers/localhost/zones/example.com.").unwrap(),
+                                                 Version::HTTP_11, &headers, &Some("{\"rrsets\":[]}".to_string())),
+                          &Ok(HttpTraceResponse::new(StatusCode::UNPROCESSABLE_ENTITY, Version::HTTP_11,
+                                                     &HeaderMap::new(), "{\"error\":\"bad\"}")))
+    }
+
+    #[test]
+    fn should_redact_api_key_in_json_line() {
+        let line = exchange().json_line();
+
+        assert_eq!(line["request_headers"]["x-api-key"], "[REDACTED]");
+        assert_eq!(line["request_headers"]["accept"], "application/json");
+        assert_eq!(line["status"], 422);
+        assert_eq!(line["response_body"], "{\"error\":\"bad\"}");
+        assert_eq!(line.to_string().contains("secret"), false)
+    }
+
+    #[test]
+    fn should_redact_api_key_in_har_entry() {
+        let entry = exchange().har_entry();
+
+        assert_eq!(entry["request"]["headers"][0]["value"], "[REDACTED]");
+        assert_eq!(entry["request"]["postData"]["text"], "{\"rrsets\":[]}");
+        assert_eq!(entry["response"]["status"], 422);
+        assert_eq!(entry.to_string().contains("secret"), false)
+    }
+
+    #[test]
+    fn should_redact_private_keys_in_bodies() {
+        let request = HttpTraceRequest::new(&Method::POST,
+                                            &Url::parse("http://localhost:8081/api/v1/servers/localhost/zones/example.com./cryptokeys").unwrap(),
+                                            Version::HTTP_11, &HeaderMap::new(),
+                                            &Some("{\"keytype\":\"csk\",\"privatekey\":\"Private-key-format: v1.2\"}".to_string()));
+        let response = HttpTraceResponse::new(StatusCode::OK, Version::HTTP_11, &HeaderMap::new(),
+                                              "[{\"id\":1,\"privatekey\":\"Private-key-format: v1.2\"}]");
+        let line = HttpExchange::new(&Utc::now(), Duration::from_millis(12), 1, &request, &Ok(response)).json_line();
+
+        assert_eq!(line["request_body"], "{\"keytype\":\"csk\",\"privatekey\":\"[REDACTED]\"}");
+        assert_eq!(line["response_body"], "[{\"id\":1,\"privatekey\":\"[REDACTED]\"}]");
+        assert!(!line.to_string().contains("Private-key-format"))
+    }
+
+    #[test]
+    fn should_write_har_file_once_on_drop() {
+        let path = std::env::temp_dir().join(format!("pdns-api-trace-{}.har", std::process::id()));
+        let http_trace = HttpTrace::new(HttpTraceFormat::Har, &Some(path.clone())).unwrap();
+
+        http_trace.record(&exchange()).unwrap();
+        http_trace.record(&exchange()).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "");
+
+        drop(http_trace);
+
+        let har: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+
+        assert_eq!(har["log"]["entries"].as_array().unwrap().len(), 2);
+        let _ = std::fs::remove_file(&path);
+    }
+}
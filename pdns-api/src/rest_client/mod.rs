@@ -15,5 +15,10 @@ pub mod server_resource_client;
 pub mod errors;
 pub mod lifecycle;
 mod client_request_builder;
+pub mod client_configuration;
+pub mod retry_policy;
 pub mod pdns_resource_client;
 pub mod zone_resource_client;
+pub mod transport;
+pub mod http_trace;
+pub mod recursor_resource_client;
@@ -0,0 +1,224 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use log::{info, warn};
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::pdns::error::Error;
+use crate::rest_client::client_configuration::ClientConfiguration;
+use crate::rest_client::client_request_builder::ClientRequestBuilder;
+use crate::rest_client::errors::RestClientError;
+use crate::rest_client::transport::TransportError;
+
+/// Generic access to the PowerDNS ReST API, resource paths are relative to the API root "api/<version>/"
+pub struct PowerDnsRestClient {
+    base_uri: String,
+    request_builder: ClientRequestBuilder,
+}
+
+impl PowerDnsRestClient {
+    pub fn new(base_uri: &String, api_key: &String, client_configuration: &ClientConfiguration) -> PowerDnsRestClient {
+        PowerDnsRestClient {
+            base_uri: base_uri.clone(),
+            request_builder: ClientRequestBuilder::new(base_uri, api_key, client_configuration),
+        }
+    }
+
+    /// Base URI as configured, used to name the server in error messages
+    pub fn base_uri(&self) -> String {
+        self.base_uri.clone()
+    }
+
+    pub async fn get<O>(&self, path: &str) -> Result<O, RestClientError> where O: DeserializeOwned {
+        info!("Executing GET request to resource {}", path);
+
+        match self.send_request(Method::GET, self.request_builder
+            .get_for_path(path)?).await {
+            Ok(rest_response) if is_success(rest_response.status()) => json_body(rest_response).await,
+            result => error_response(result).await,
+        }
+    }
+
+    pub async fn get_text(&self, path: &str) -> Result<String, RestClientError> {
+        info!("Executing GET request to text resource {}", path);
+
+        match self.send_request(Method::GET, self.request_builder
+            .get_text_for_path(path)?).await {
+            Ok(rest_response) if is_success(rest_response.status()) => match rest_response.text().await {
+                Ok(server_response) => Ok(server_response),
+                Err(rest_err) => Err(RestClientError::on_reqwest_runtime_error(rest_err.to_string())),
+            },
+            result => error_response(result).await,
+        }
+    }
+
+    pub async fn post<O, T>(&self, path: &str, payload: &T) -> Result<O, RestClientError> where O: DeserializeOwned, T: Serialize {
+        info!("Executing POST request to resource {} with payload {}",
+            path,
+            serde_json::to_string(payload).unwrap());
+
+        match self.send_request(Method::POST, self.request_builder
+            .post_for_path(path)?
+            .json(payload)).await {
+            Ok(rest_response) if is_success(rest_response.status()) => json_body(rest_response).await,
+            result => error_response(result).await,
+        }
+    }
+
+    /// POST of a payload carrying secrets like private keys, the payload is left out of the log
+    pub async fn post_sensitive<O, T>(&self, path: &str, payload: &T) -> Result<O, RestClientError> where O: DeserializeOwned, T: Serialize {
+        info!("Executing POST request to resource {} with withheld payload", path);
+
+        match self.send_request(Method::POST, self.request_builder
+            .post_for_path(path)?
+            .json(payload)).await {
+            Ok(rest_response) if is_success(rest_response.status()) => json_body(rest_response).await,
+            result => error_response(result).await,
+        }
+    }
+
+    /// POST answered without response body, like the creation of an autoprimary
+    pub async fn post_no_content<T>(&self, path: &str, payload: &T) -> Result<(), RestClientError> where T: Serialize {
+        info!("Executing POST request to resource {} with payload {}",
+            path,
+            serde_json::to_string(payload).unwrap());
+
+        match self.send_request(Method::POST, self.request_builder
+            .post_for_path(path)?
+            .json(payload)).await {
+            Ok(rest_response) if is_success(rest_response.status()) => Ok(()),
+            result => error_response(result).await,
+        }
+    }
+
+    pub async fn put<T>(&self, path: &str, payload: &T) -> Result<(), RestClientError> where T: Serialize {
+        info!("Executing PUT request to resource {} with payload {}",
+            path,
+            serde_json::to_string(payload).unwrap());
+
+        match self.send_request(Method::PUT, self.request_builder
+            .put_for_path(path)?
+            .json(payload)).await {
+            Ok(rest_response) if is_success(rest_response.status()) => Ok(()),
+            result => error_response(result).await,
+        }
+    }
+
+    /// PUT without request body, used by the zone actions like rectify or notify
+    pub async fn put_action<O>(&self, path: &str) -> Result<O, RestClientError> where O: DeserializeOwned {
+        info!("Executing PUT request to action resource {}", path);
+
+        match self.send_request(Method::PUT, self.request_builder
+            .put_for_path(path)?).await {
+            Ok(rest_response) if is_success(rest_response.status()) => json_body(rest_response).await,
+            result => error_response(result).await,
+        }
+    }
+
+    pub async fn patch<T>(&self, path: &str, payload: &T) -> Result<(), RestClientError> where T: Serialize {
+        info!("Executing PATCH request to resource {} with payload {}",
+            path,
+            serde_json::to_string(payload).unwrap());
+
+        match self.send_request(Method::PATCH, self.request_builder
+            .patch_for_path(path)?
+            .json(payload)).await {
+            Ok(rest_response) if is_success(rest_response.status()) => Ok(()),
+            result => error_response(result).await,
+        }
+    }
+
+    pub async fn delete(&self, path: &str) -> Result<(), RestClientError> {
+        info!("Executing DELETE request to resource {}", path);
+
+        match self.send_request(Method::DELETE, self.request_builder
+            .delete_for_path(path)?).await {
+            Ok(rest_response) if is_success(rest_response.status()) => Ok(()),
+            result => error_response(result).await,
+        }
+    }
+
+    /// Send the request, repeating it according to the retry policy while it fails transiently
+    async fn send_request(&self, method: Method, request: RequestBuilder) -> Result<Response, TransportError> {
+        let retry_policy = self.request_builder.retry_policy();
+        let request = request.build().map_err(TransportError::from)?;
+        let mut attempt: u32 = 1;
+
+        loop {
+            let result = match request.try_clone() {
+                Some(request) => self.request_builder.send(request, attempt).await,
+                None => return self.request_builder.send(request, attempt).await,
+            };
+
+            if attempt >= retry_policy.max_attempts() || !retry_policy.is_retryable(&method, &result) {
+                return result;
+            }
+
+            let backoff = retry_policy.backoff(attempt);
+
+            match &result {
+                Ok(response) => warn!("{} request failed with status {} on attempt {}, retrying in {} ms",
+                    &method, response.status(), attempt, backoff.as_millis()),
+                Err(error) => warn!("{} request failed with error {} on attempt {}, retrying in {} ms",
+                    &method, error, attempt, backoff.as_millis()),
+            }
+
+            tokio::time::sleep(backoff).await;
+            attempt += 1;
+        }
+    }
+}
+
+async fn json_body<O>(rest_response: Response) -> Result<O, RestClientError> where O: DeserializeOwned {
+    match rest_response.json::<O>().await {
+        Ok(server_response) => Ok(server_response),
+        Err(rest_err) => Err(RestClientError::on_reqwest_runtime_error(rest_err.to_string())),
+    }
+}
+
+/// Map a failed or unsuccessful response to the matching client error
+async fn error_response<O>(result: Result<Response, TransportError>) -> Result<O, RestClientError> {
+    match result {
+        Ok(rest_response) if is_known_error(rest_response.status()) => {
+            let status_code = rest_response.status();
+
+            match rest_response.json::<Error>().await {
+                Ok(server_response) => Err(RestClientError::on_powerdns_server_error(status_code, server_response)),
+                Err(rest_err) => Err(RestClientError::on_reqwest_runtime_error(rest_err.to_string())),
+            }
+        }
+        Ok(rest_response) => Err(RestClientError::on_client_error(rest_response.status())),
+        Err(rest_err) => Err(RestClientError::on_reqwest_runtime_error(rest_err.to_string())),
+    }
+}
+
+fn is_known_error(status_code: StatusCode) -> bool {
+    match status_code {
+        StatusCode::BAD_REQUEST => true,
+        StatusCode::NOT_FOUND => true,
+        StatusCode::UNPROCESSABLE_ENTITY => true,
+        StatusCode::INTERNAL_SERVER_ERROR => true,
+        _ => false,
+    }
+}
+
+fn is_success(status_code: StatusCode) -> bool {
+    match status_code {
+        StatusCode::OK => true,
+        StatusCode::CREATED => true,
+        StatusCode::NO_CONTENT => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::pdns::recursor::{NewForwardZone, RecursorZone, RpzStatistics};
+use crate::rest_client::errors::RestClientError;
+use crate::rest_client::pdns_resource_client::PowerDnsRestClient;
+
+/// Typed access to the resources only offered by the PowerDNS recursor
+#[derive(Clone)]
+pub struct RecursorResourceClient {
+    pdns_resource_client: Arc<PowerDnsRestClient>,
+}
+
+impl RecursorResourceClient {
+    pub fn new(pdns_resource_client: &Arc<PowerDnsRestClient>) -> RecursorResourceClient {
+        RecursorResourceClient {
+            pdns_resource_client: pdns_resource_client.clone(),
+        }
+    }
+
+    pub async fn list_zones(&self) -> Result<Vec<RecursorZone>, RestClientError> {
+        self.pdns_resource_client.get::<Vec<RecursorZone>>(&zones_request_path()).await
+    }
+
+    pub async fn get_zone(&self, zone_name: &String) -> Result<RecursorZone, RestClientError> {
+        self.pdns_resource_client.get::<RecursorZone>(&zone_request_path(zone_name)).await
+    }
+
+    pub async fn create_forward_zone(&self, zone_name: &String, servers: &Vec<String>,
+                                     recursion_desired: bool) -> Result<RecursorZone, RestClientError> {
+        self.pdns_resource_client.post::<RecursorZone, NewForwardZone>(&zones_request_path(),
+                                                                       &NewForwardZone::new(zone_name, servers, recursion_desired)).await
+    }
+
+    pub async fn remove_zone(&self, zone_name: &String) -> Result<(), RestClientError> {
+        self.pdns_resource_client.delete(&zone_request_path(zone_name)).await
+    }
+
+    /// Statistics of the response policy zones keyed by zone name
+    pub async fn get_rpz_statistics(&self) -> Result<BTreeMap<String, RpzStatistics>, RestClientError> {
+        self.pdns_resource_client.get::<BTreeMap<String, RpzStatistics>>(&rpz_statistics_request_path()).await
+    }
+}
+
+fn zones_request_path() -> String {
+    "servers/localhost/zones".to_string()
+}
+
+fn zone_request_path(zone_name: &String) -> String {
+    format!("servers/localhost/zones/{}", zone_name)
+}
+
+fn rpz_statistics_request_path() -> String {
+    "servers/localhost/rpzstatistics".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rest_client::recursor_resource_client::{rpz_statistics_request_path, zone_request_path, zones_request_path};
+
+    #[test]
+    fn should_build_recursor_request_paths() {
+        assert_eq!(zones_request_path(), "servers/localhost/zones");
+        assert_eq!(zone_request_path(&"example.com.".to_string()), "servers/localhost/zones/example.com.");
+        assert_eq!(rpz_statistics_request_path(), "servers/localhost/rpzstatistics")
+    }
+}
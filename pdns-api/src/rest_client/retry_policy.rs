@@ -0,0 +1,173 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::{Method, Response, StatusCode};
+
+use crate::rest_client::transport::TransportError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    retryable_status_codes: Vec<StatusCode>,
+}
+
+impl RetryPolicy {
+    pub fn new(max_attempts: u32, initial_backoff: Duration, max_backoff: Duration, jitter: bool,
+               retryable_status_codes: &Vec<StatusCode>) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            initial_backoff,
+            max_backoff,
+            jitter,
+            retryable_status_codes: retryable_status_codes.clone(),
+        }
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    pub fn retryable_status_codes(&self) -> Vec<StatusCode> {
+        self.retryable_status_codes.clone()
+    }
+
+    /// Delay before the given retry, doubling with each retry up to the maximum backoff.
+    /// With jitter enabled a random delay between zero and the computed backoff is chosen.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let backoff = self.initial_backoff
+            .checked_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+
+        match self.jitter {
+            true if !backoff.is_zero() => rand::thread_rng().gen_range(Duration::ZERO..=backoff),
+            _ => backoff,
+        }
+    }
+
+    /// Decide whether a failed request may be sent again. POST requests are not idempotent and
+    /// are only repeated if the connection could not be established, the server never saw them.
+    pub fn is_retryable(&self, method: &Method, result: &Result<Response, TransportError>) -> bool {
+        match result {
+            Err(TransportError::Connect { .. }) => true,
+            _ if method == Method::POST => false,
+            Err(TransportError::Timeout { .. }) | Err(TransportError::Request { .. }) => true,
+            Err(TransportError::Other { .. }) => false,
+            Ok(response) => self.retryable_status_codes.contains(&response.status()),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new(3, Duration::from_millis(250), Duration::from_secs(5), true,
+                         &vec![StatusCode::TOO_MANY_REQUESTS, StatusCode::BAD_GATEWAY,
+                               StatusCode::SERVICE_UNAVAILABLE, StatusCode::GATEWAY_TIMEOUT])
+    }
+}
+
+impl Display for RetryPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let status_codes: Vec<String> = self.retryable_status_codes.iter()
+            .map(|status_code| status_code.as_u16().to_string())
+            .collect();
+
+        write!(f, "(max_attempts: {}, initial_backoff: {} ms, max_backoff: {} ms, jitter: {}, retryable_status_codes: ({}))",
+               self.max_attempts, self.initial_backoff.as_millis(), self.max_backoff.as_millis(),
+               self.jitter, status_codes.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::{Method, Response, StatusCode};
+
+    use crate::rest_client::retry_policy::RetryPolicy;
+    use crate::rest_client::transport::TransportError;
+
+    fn response(status_code: StatusCode) -> Result<Response, TransportError> {
+        Ok(Response::from(hyper::Response::builder().status(status_code).body("").unwrap()))
+    }
+
+    fn error(error: fn(String) -> TransportError) -> Result<Response, TransportError> {
+        Err(error("failed".to_string()))
+    }
+
+    #[test]
+    fn should_double_backoff_up_to_maximum() {
+        let policy = RetryPolicy::new(5, Duration::from_millis(100), Duration::from_millis(350), false, &Vec::new());
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(350));
+        assert_eq!(policy.backoff(40), Duration::from_millis(350))
+    }
+
+    #[test]
+    fn should_keep_jittered_backoff_within_bounds() {
+        let policy = RetryPolicy::new(5, Duration::from_millis(100), Duration::from_secs(1), true, &Vec::new());
+
+        for retry in 1..5 {
+            assert_eq!(policy.backoff(retry) <= Duration::from_millis(100 * 2u64.pow(retry - 1)), true)
+        }
+    }
+
+    #[test]
+    fn should_decide_retry_by_method_and_result() {
+        let policy = RetryPolicy::default();
+        let connect = |message| TransportError::Connect { message };
+        let timeout = |message| TransportError::Timeout { message };
+        let request = |message| TransportError::Request { message };
+        let other = |message| TransportError::Other { message };
+        let cases = vec![
+            (Method::GET, error(connect), true),
+            (Method::GET, error(timeout), true),
+            (Method::GET, error(request), true),
+            (Method::GET, error(other), false),
+            (Method::GET, response(StatusCode::SERVICE_UNAVAILABLE), true),
+            (Method::GET, response(StatusCode::TOO_MANY_REQUESTS), true),
+            (Method::GET, response(StatusCode::INTERNAL_SERVER_ERROR), false),
+            (Method::GET, response(StatusCode::OK), false),
+            (Method::PATCH, error(timeout), true),
+            (Method::PATCH, response(StatusCode::BAD_GATEWAY), true),
+            (Method::PUT, error(request), true),
+            (Method::DELETE, response(StatusCode::GATEWAY_TIMEOUT), true),
+            (Method::POST, error(connect), true),
+            (Method::POST, error(timeout), false),
+            (Method::POST, error(request), false),
+            (Method::POST, error(other), false),
+            (Method::POST, response(StatusCode::SERVICE_UNAVAILABLE), false),
+        ];
+
+        for (method, result, retryable) in cases.iter() {
+            assert_eq!(policy.is_retryable(method, result), *retryable, "{} {:?}", method, result.as_ref().map(|response| response.status()))
+        }
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use log::warn;
+use reqwest::Url;
+
+use crate::pdns::autoprimary::Autoprimary;
+use crate::pdns::cache::CacheFlushResult;
+use crate::pdns::config::{ConfigSetting, ConfigValue};
+use crate::pdns::server::{DaemonType, Server};
+use crate::pdns::statistics::Statistic;
+use crate::pdns::version::Capability;
+use crate::rest_client::errors::RestClientError;
+use crate::rest_client::pdns_resource_client::PowerDnsRestClient;
+
+/// Typed access to the server resource of the PowerDNS API
+#[derive(Clone)]
+pub struct ServerResourceClient {
+    pdns_resource_client: Arc<PowerDnsRestClient>,
+}
+
+impl ServerResourceClient {
+    pub fn new(pdns_resource_client: &Arc<PowerDnsRestClient>) -> ServerResourceClient {
+        ServerResourceClient {
+            pdns_resource_client: pdns_resource_client.clone(),
+        }
+    }
+
+    pub fn base_uri(&self) -> String {
+        self.pdns_resource_client.base_uri()
+    }
+
+    pub async fn get_server(&self) -> Result<Server, RestClientError> {
+        self.pdns_resource_client.get::<Server>(&get_server_request_path()).await
+    }
+
+    /// Read the server, failing unless it runs the expected daemon
+    pub async fn get_server_of_type(&self, daemon_type: DaemonType) -> Result<Server, RestClientError> {
+        match self.get_server().await {
+            Ok(server) if server.daemon_type() == daemon_type => Ok(server),
+            Ok(server) => Err(RestClientError::on_wrong_daemon_type(&self.pdns_resource_client.base_uri(),
+                                                                    &server.version(), server.daemon_type(), daemon_type)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Fail unless the server version offers the capability. Servers reporting a version outside
+    /// the release scheme, like builds from the master branch, are assumed to offer all capabilities.
+    pub fn require_capability(&self, server: &Server, capability: Capability) -> Result<(), RestClientError> {
+        match server.server_version() {
+            Some(version) if version.supports(capability) => Ok(()),
+            Some(_) => Err(RestClientError::on_unsupported_server_version(&self.pdns_resource_client.base_uri(),
+                                                                          &server.version(),
+                                                                          &capability.to_string(),
+                                                                          &capability.required_version().to_string())),
+            None => {
+                warn!("Unknown version {} of server {}, assuming it supports {}",
+                      server.version(), self.pdns_resource_client.base_uri(), capability);
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Fail unless the server version offers the capability and the setting it depends on is enabled
+    pub async fn require_enabled_capability(&self, server: &Server, capability: Capability) -> Result<(), RestClientError> {
+        self.require_capability(server, capability)?;
+
+        match capability.required_setting() {
+            Some(name) => match is_setting_enabled(&self.get_config().await?, name) {
+                true => Ok(()),
+                false => Err(RestClientError::on_feature_not_enabled(&self.pdns_resource_client.base_uri(),
+                                                                     &server.version(), &capability.to_string())),
+            },
+            None => Ok(()),
+        }
+    }
+
+    pub async fn get_config(&self) -> Result<Vec<ConfigSetting>, RestClientError> {
+        self.pdns_resource_client.get::<Vec<ConfigSetting>>(&config_request_path()).await
+    }
+
+    pub async fn get_config_setting(&self, name: &String) -> Result<ConfigSetting, RestClientError> {
+        self.pdns_resource_client.get::<ConfigSetting>(&config_setting_request_path(name)).await
+    }
+
+    /// Read all statistics or only the named one, the ring buffers are left out unless requested
+    pub async fn get_statistics(&self, statistic: &Option<String>, include_rings: bool) -> Result<Vec<Statistic>, RestClientError> {
+        self.pdns_resource_client.get::<Vec<Statistic>>(&statistics_request_path(statistic, include_rings)).await
+    }
+
+    pub async fn list_autoprimaries(&self) -> Result<Vec<Autoprimary>, RestClientError> {
+        self.pdns_resource_client.get::<Vec<Autoprimary>>(&autoprimaries_request_path()).await
+    }
+
+    pub async fn add_autoprimary(&self, autoprimary: &Autoprimary) -> Result<(), RestClientError> {
+        self.pdns_resource_client.post_no_content::<Autoprimary>(&autoprimaries_request_path(), autoprimary).await
+    }
+
+    pub async fn remove_autoprimary(&self, ip: &String, nameserver: &String) -> Result<(), RestClientError> {
+        self.pdns_resource_client.delete(&autoprimary_request_path(ip, nameserver)).await
+    }
+
+    /// Remove the cache entries of the absolute domain name, optionally only those of the record type.
+    /// The recursor also removes the entries of all names below the domain if subtree is set.
+    pub async fn flush_cache(&self, domain: &String, subtree: bool, record_type: &Option<String>) -> Result<CacheFlushResult, RestClientError> {
+        self.pdns_resource_client.put_action::<CacheFlushResult>(&cache_flush_request_path(domain, subtree, record_type)).await
+    }
+}
+
+/// Boolean settings are disabled by 'no', 'off' or 'false', a setting missing from the configuration keeps its default
+fn is_setting_enabled(settings: &[ConfigSetting], name: &str) -> bool {
+    match settings.iter().find(|setting| setting.name() == name).map(|setting| setting.value()) {
+        Some(ConfigValue::Single(value)) => !matches!(value.to_lowercase().as_str(), "" | "no" | "off" | "false" | "0"),
+        _ => false,
+    }
+}
+
+fn get_server_request_path() -> String {
+    "servers/localhost".to_string()
+}
+
+/// Append the URL encoded query parameters to a request path
+fn query_request_path(path: &str, parameters: &[(&str, &str)]) -> String {
+    let mut url = Url::parse("http://localhost/").unwrap();
+
+    url.query_pairs_mut().extend_pairs(parameters);
+
+    format!("{}?{}", path, url.query().unwrap_or_default())
+}
+
+/// Append the URL encoded path segments to a request path
+fn segment_request_path(path: &str, segments: &[&str]) -> String {
+    segments.iter()
+        .fold(path.to_string(), |path, segment| format!("{}/{}", path, encode_path_segment(segment)))
+}
+
+/// Percent-encode all but the unreserved characters and the colons of IPv6 addresses. The dot segments
+/// '.' and '..' are encoded as well, as they would otherwise change the request path.
+fn encode_path_segment(segment: &str) -> String {
+    match segment {
+        "." | ".." => segment.replace('.', "%2E"),
+        _ => segment.bytes()
+            .map(|byte| match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b':' => (byte as char).to_string(),
+                _ => format!("%{:02X}", byte),
+            })
+            .collect(),
+    }
+}
+
+fn statistics_request_path(statistic: &Option<String>, include_rings: bool) -> String {
+    let include_rings = include_rings.to_string();
+    let mut parameters = Vec::new();
+
+    if let Some(statistic) = statistic {
+        parameters.push(("statistic", statistic.as_str()));
+    }
+    parameters.push(("includerings", include_rings.as_str()));
+
+    query_request_path(&format!("{}/statistics", get_server_request_path()), &parameters)
+}
+
+fn config_request_path() -> String {
+    format!("{}/config", get_server_request_path())
+}
+
+fn config_setting_request_path(name: &String) -> String {
+    format!("{}/{}", config_request_path(), name)
+}
+
+fn autoprimaries_request_path() -> String {
+    format!("{}/autoprimaries", get_server_request_path())
+}
+
+fn autoprimary_request_path(ip: &String, nameserver: &String) -> String {
+    segment_request_path(&autoprimaries_request_path(), &[ip.as_str(), nameserver.as_str()])
+}
+
+fn cache_flush_request_path(domain: &String, subtree: bool, record_type: &Option<String>) -> String {
+    let mut parameters = vec![("domain", domain.as_str())];
+
+    if subtree {
+        parameters.push(("subtree", "true"));
+    }
+    if let Some(record_type) = record_type {
+        parameters.push(("type", record_type.as_str()));
+    }
+
+    query_request_path(&format!("{}/cache/flush", get_server_request_path()), &parameters)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pdns::config::{ConfigSetting, ConfigValue};
+    use crate::rest_client::server_resource_client::{autoprimary_request_path, cache_flush_request_path, is_setting_enabled,
+                                                     statistics_request_path};
+
+    #[test]
+    fn should_encode_statistics_query() {
+        assert_eq!(statistics_request_path(&None, false), "servers/localhost/statistics?includerings=false");
+        assert_eq!(statistics_request_path(&Some("uptime".to_string()), true),
+                   "servers/localhost/statistics?statistic=uptime&includerings=true");
+        assert_eq!(statistics_request_path(&Some("a&b=c d".to_string()), false),
+                   "servers/localhost/statistics?statistic=a%26b%3Dc+d&includerings=false")
+    }
+
+    #[test]
+    fn should_encode_cache_flush_query() {
+        assert_eq!(cache_flush_request_path(&"example.com.".to_string(), false, &None),
+                   "servers/localhost/cache/flush?domain=example.com.");
+        assert_eq!(cache_flush_request_path(&"example.com.".to_string(), true, &Some("AAAA".to_string())),
+                   "servers/localhost/cache/flush?domain=example.com.&subtree=true&type=AAAA");
+        assert_eq!(cache_flush_request_path(&"a&type=ANY.example.com.".to_string(), false, &None),
+                   "servers/localhost/cache/flush?domain=a%26type%3DANY.example.com.")
+    }
+
+    #[test]
+    fn should_encode_autoprimary_path() {
+        assert_eq!(autoprimary_request_path(&"192.0.2.1".to_string(), &"ns1.example.org.".to_string()),
+                   "servers/localhost/autoprimaries/192.0.2.1/ns1.example.org.");
+        assert_eq!(autoprimary_request_path(&"2001:db8::1".to_string(), &"ns1.example.org.".to_string()),
+                   "servers/localhost/autoprimaries/2001:db8::1/ns1.example.org.");
+        assert_eq!(autoprimary_request_path(&"192.0.2.1".to_string(), &"../config?x#y".to_string()),
+                   "servers/localhost/autoprimaries/192.0.2.1/..%2Fconfig%3Fx%23y");
+        assert_eq!(autoprimary_request_path(&"..".to_string(), &".".to_string()),
+                   "servers/localhost/autoprimaries/%2E%2E/%2E")
+    }
+
+    #[test]
+    fn should_check_boolean_setting() {
+        let setting = |value: &str| vec![ConfigSetting::new(&"enable-lua-records".to_string(), &ConfigValue::Single(value.to_string()))];
+
+        assert!(is_setting_enabled(&setting("yes"), "enable-lua-records"));
+        assert!(is_setting_enabled(&setting("shared"), "enable-lua-records"));
+        assert!(!is_setting_enabled(&setting("no"), "enable-lua-records"));
+        assert!(!is_setting_enabled(&setting(""), "enable-lua-records"));
+        assert!(!is_setting_enabled(&Vec::new(), "enable-lua-records"))
+    }
+}
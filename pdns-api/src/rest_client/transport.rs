@@ -0,0 +1,268 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use hyper::client::connect::{Connected, Connection};
+use hyper::{Body, Uri};
+use reqwest::{Client, Request, Response};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::UnixStream;
+use tower_service::Service;
+
+/// Scheme of base URIs naming a Unix socket, e.g. 'unix:///run/pdns/api.sock'
+pub const UNIX_SOCKET_SCHEME: &'static str = "unix";
+
+/// How requests reach the PowerDNS webserver: over TCP by the shared reqwest client or over a
+/// local Unix socket, e.g. when the webserver is published by a reverse proxy on the same host.
+#[derive(Clone)]
+pub enum Transport {
+    Http {
+        client: Client,
+    },
+    UnixSocket {
+        socket_path: PathBuf,
+        client: Box<hyper::Client<UnixSocketConnector, Body>>,
+        request_timeout: Duration,
+    },
+}
+
+/// Failure to exchange a request with the server, classified for the retry policy
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransportError {
+    Connect { message: String },
+    Timeout { message: String },
+    Request { message: String },
+    Other { message: String },
+}
+
+/// Connector opening a new connection to the Unix socket for every pooled connection
+#[derive(Clone, Debug)]
+pub struct UnixSocketConnector {
+    socket_path: PathBuf,
+    connect_timeout: Duration,
+}
+
+pub struct UnixSocketStream {
+    stream: UnixStream,
+}
+
+impl Transport {
+    pub fn unix_socket(socket_path: &Path, connect_timeout: Duration, request_timeout: Duration) -> Transport {
+        Transport::UnixSocket {
+            socket_path: socket_path.to_path_buf(),
+            client: Box::new(hyper::Client::builder().build(UnixSocketConnector {
+                socket_path: socket_path.to_path_buf(),
+                connect_timeout,
+            })),
+            request_timeout,
+        }
+    }
+
+    pub async fn send(&self, request: Request) -> Result<Response, TransportError> {
+        match self {
+            Transport::Http { client } => client.execute(request).await.map_err(TransportError::from),
+            Transport::UnixSocket { socket_path: _, client, request_timeout } => {
+                let mut builder = hyper::Request::builder()
+                    .method(request.method().clone())
+                    .uri(request.url().as_str());
+
+                for (name, value) in request.headers().iter() {
+                    builder = builder.header(name, value);
+                }
+
+                let body = match request.body().and_then(|body| body.as_bytes()) {
+                    Some(bytes) => Body::from(bytes.to_vec()),
+                    None => Body::empty(),
+                };
+                let unix_request = builder.body(body)
+                    .map_err(|error| TransportError::Request { message: error.to_string() })?;
+
+                match tokio::time::timeout(*request_timeout, client.request(unix_request)).await {
+                    Ok(Ok(response)) => Ok(Response::from(response)),
+                    Ok(Err(error)) if error.is_connect() => Err(TransportError::Connect { message: error.to_string() }),
+                    Ok(Err(error)) => Err(TransportError::Request { message: error.to_string() }),
+                    Err(_) => Err(TransportError::Timeout {
+                        message: format!("no response within {} s", request_timeout.as_secs())
+                    }),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Transport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Transport::Http { .. } => write!(f, "http"),
+            Transport::UnixSocket { socket_path, .. } => write!(f, "unix socket {}", socket_path.display()),
+        }
+    }
+}
+
+impl From<reqwest::Error> for TransportError {
+    fn from(error: reqwest::Error) -> Self {
+        let message = error.to_string();
+
+        if error.is_connect() {
+            TransportError::Connect { message }
+        } else if error.is_timeout() {
+            TransportError::Timeout { message }
+        } else if error.is_request() {
+            TransportError::Request { message }
+        } else {
+            TransportError::Other { message }
+        }
+    }
+}
+
+impl fmt::Display for TransportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TransportError::Connect { message } => write!(f, "connect error: {}", message),
+            TransportError::Timeout { message } => write!(f, "timeout: {}", message),
+            TransportError::Request { message } => write!(f, "request error: {}", message),
+            TransportError::Other { message } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Service<Uri> for UnixSocketConnector {
+    type Response = UnixSocketStream;
+    type Error = io::Error;
+    type Future = Pin<Box<dyn Future<Output=Result<UnixSocketStream, io::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), io::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, _uri: Uri) -> Self::Future {
+        let socket_path = self.socket_path.clone();
+        let connect_timeout = self.connect_timeout;
+
+        Box::pin(async move {
+            match tokio::time::timeout(connect_timeout, UnixStream::connect(&socket_path)).await {
+                Ok(stream) => Ok(UnixSocketStream { stream: stream? }),
+                Err(_) => Err(io::Error::new(io::ErrorKind::TimedOut,
+                                             format!("connect to {} timed out", socket_path.display()))),
+            }
+        })
+    }
+}
+
+impl Connection for UnixSocketStream {
+    fn connected(&self) -> Connected {
+        Connected::new()
+    }
+}
+
+impl AsyncRead for UnixSocketStream {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for UnixSocketStream {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.stream).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_shutdown(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use reqwest::{Client, Request, StatusCode};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::UnixListener;
+
+    use crate::rest_client::transport::{Transport, TransportError};
+
+    fn socket_path(name: &str) -> PathBuf {
+        let socket_path = std::env::temp_dir().join(format!("pdns-api-{}-{}.sock", name, std::process::id()));
+        let _ = std::fs::remove_file(&socket_path);
+
+        socket_path
+    }
+
+    fn request() -> Request {
+        Client::new().get("http://localhost/api/v1/servers").build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_send_request_over_unix_socket() {
+        let socket_path = socket_path("send");
+        let listener = UnixListener::bind(&socket_path).unwrap();
+
+        tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buffer = [0u8; 1024];
+            let _ = stream.read(&mut buffer).await.unwrap();
+
+            stream.write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\n\r\n[]").await.unwrap();
+        });
+
+        let transport = Transport::unix_socket(&socket_path, Duration::from_secs(1), Duration::from_secs(1));
+        let response = transport.send(request()).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.text().await.unwrap(), "[]");
+        let _ = std::fs::remove_file(&socket_path);
+    }
+
+    #[tokio::test]
+    async fn should_fail_connect_to_missing_socket() {
+        let transport = Transport::unix_socket(&socket_path("missing"), Duration::from_secs(1), Duration::from_secs(1));
+
+        assert!(matches!(transport.send(request()).await, Err(TransportError::Connect { .. })));
+    }
+
+    #[tokio::test]
+    async fn should_time_out_without_response() {
+        let socket_path = socket_path("timeout");
+        let listener = UnixListener::bind(&socket_path).unwrap();
+
+        tokio::spawn(async move {
+            let (_stream, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(5)).await;
+        });
+
+        let transport = Transport::unix_socket(&socket_path, Duration::from_secs(1), Duration::from_millis(100));
+
+        assert!(matches!(transport.send(request()).await, Err(TransportError::Timeout { .. })));
+        let _ = std::fs::remove_file(&socket_path);
+    }
+
+    #[test]
+    fn should_display_transport() {
+        let transport = Transport::unix_socket(&PathBuf::from("/run/pdns/api.sock"), Duration::from_secs(1), Duration::from_secs(1));
+
+        assert_eq!(transport.to_string(), "unix socket /run/pdns/api.sock");
+        assert_eq!(Transport::Http { client: Client::new() }.to_string(), "http")
+    }
+}
@@ -0,0 +1,577 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use log::info;
+
+use crate::pdns::cryptokey::{Cryptokey, NewCryptokey};
+use crate::pdns::dns_name::DnsName;
+use crate::pdns::metadata::Metadata;
+use crate::pdns::soa::SerialPolicy;
+use crate::pdns::version::Capability;
+use crate::pdns::zone::{Changetype, NewZone, Record, Rrset, Rrsets, RrsetType, Zone, ZoneActionResult, ZoneKind, ZoneUpdate, ListZone};
+use crate::rest_client::errors::RestClientError;
+use crate::rest_client::pdns_resource_client::PowerDnsRestClient;
+
+/// Typed access to the zone resources of the PowerDNS API
+#[derive(Clone)]
+pub struct ZoneResourceClient {
+    pdns_resource_client: Arc<PowerDnsRestClient>,
+}
+
+pub struct CreateZoneRequest {
+    zone_name: String,
+    refresh: u32,
+    retry: u32,
+    expire: u32,
+    neg_caching: u32,
+    kind: String,
+    masters: Vec<String>,
+    nameservers: Vec<String>,
+    hostmaster: Option<String>,
+    account: Option<String>,
+    soa_edit: Option<String>,
+    soa_edit_api: Option<String>,
+    api_rectify: bool,
+    dnssec: bool,
+    nsec3param: Option<String>,
+    catalog: Option<String>,
+    serial_policy: SerialPolicy,
+}
+
+pub struct UpdateZoneRequest {
+    zone_name: String,
+    kind: Option<String>,
+    masters: Option<Vec<String>>,
+    account: Option<String>,
+    soa_edit: Option<String>,
+    soa_edit_api: Option<String>,
+    api_rectify: Option<bool>,
+    dnssec: Option<bool>,
+    nsec3param: Option<String>,
+    catalog: Option<String>,
+}
+
+pub struct AddEntryRequest {
+    zone_name: String,
+    record_key: String,
+    record_type: String,
+    record_values: Vec<String>,
+    time_to_live: u32,
+    soa_rrset: Option<Rrset>,
+}
+
+pub struct RemoveEntryRequest {
+    zone_name: String,
+    record_key: String,
+    record_type: String,
+    soa_rrset: Option<Rrset>,
+}
+
+impl ZoneResourceClient {
+    pub fn new(pdns_resource_client: &Arc<PowerDnsRestClient>) -> ZoneResourceClient {
+        ZoneResourceClient {
+            pdns_resource_client: pdns_resource_client.clone(),
+        }
+    }
+
+    pub async fn get_zone(&self, zone_name: &String) -> Result<Zone, RestClientError> {
+        self.pdns_resource_client.get::<Zone>(&zone_request_path(zone_name)).await
+    }
+
+    pub async fn list_zones(&self) -> Result<Vec<ListZone>, RestClientError> {
+        self.pdns_resource_client.get::<Vec<ListZone>>(&zones_request_path()).await
+    }
+
+    pub async fn create_zone(&self, request: &CreateZoneRequest) -> Result<Zone, RestClientError> {
+        self.pdns_resource_client.post::<Zone, NewZone>(&zones_request_path(),
+                                                         &create_zone_body_provider(request)?).await
+    }
+
+    pub async fn update_zone(&self, request: &UpdateZoneRequest) -> Result<(), RestClientError> {
+        self.pdns_resource_client.put::<ZoneUpdate>(&zone_request_path(&request.zone_name),
+                                                    &update_zone_body_provider(request)?).await
+    }
+
+    /// Create a zone from a complete zone description, e.g. taken from a backup
+    pub async fn import_zone(&self, zone: &NewZone) -> Result<Zone, RestClientError> {
+        self.pdns_resource_client.post::<Zone, NewZone>(&zones_request_path(), zone).await
+    }
+
+    pub async fn apply_zone_update(&self, zone_name: &String, update: &ZoneUpdate) -> Result<(), RestClientError> {
+        self.pdns_resource_client.put::<ZoneUpdate>(&zone_request_path(zone_name), update).await
+    }
+
+    pub async fn list_metadata(&self, zone_name: &String) -> Result<Vec<Metadata>, RestClientError> {
+        self.pdns_resource_client.get::<Vec<Metadata>>(&zone_action_request_path(zone_name, "metadata")).await
+    }
+
+    /// Replace all values of the metadata kind
+    pub async fn replace_metadata(&self, zone_name: &String, metadata: &Metadata) -> Result<(), RestClientError> {
+        self.pdns_resource_client.put::<Metadata>(&metadata_request_path(zone_name, &metadata.kind()), metadata).await
+    }
+
+    /// Keys of the zone, without private keys
+    pub async fn list_cryptokeys(&self, zone_name: &String) -> Result<Vec<Cryptokey>, RestClientError> {
+        self.pdns_resource_client.get::<Vec<Cryptokey>>(&zone_action_request_path(zone_name, "cryptokeys")).await
+    }
+
+    /// Single key including its private key
+    pub async fn get_cryptokey(&self, zone_name: &String, key_id: u32) -> Result<Cryptokey, RestClientError> {
+        self.pdns_resource_client.get::<Cryptokey>(&cryptokey_request_path(zone_name, key_id)).await
+    }
+
+    pub async fn import_cryptokey(&self, zone_name: &String, cryptokey: &NewCryptokey) -> Result<Cryptokey, RestClientError> {
+        self.pdns_resource_client.post_sensitive::<Cryptokey, NewCryptokey>(&zone_action_request_path(zone_name, "cryptokeys"),
+                                                                            cryptokey).await
+    }
+
+    pub async fn rectify_zone(&self, zone_name: &String) -> Result<ZoneActionResult, RestClientError> {
+        self.pdns_resource_client.put_action::<ZoneActionResult>(&zone_action_request_path(zone_name, "rectify")).await
+    }
+
+    pub async fn notify_zone(&self, zone_name: &String) -> Result<ZoneActionResult, RestClientError> {
+        self.pdns_resource_client.put_action::<ZoneActionResult>(&zone_action_request_path(zone_name, "notify")).await
+    }
+
+    pub async fn axfr_retrieve_zone(&self, zone_name: &String) -> Result<ZoneActionResult, RestClientError> {
+        self.pdns_resource_client.put_action::<ZoneActionResult>(&zone_action_request_path(zone_name, "axfr-retrieve")).await
+    }
+
+    /// Zone contents in AXFR text format
+    pub async fn export_zone(&self, zone_name: &String) -> Result<String, RestClientError> {
+        self.pdns_resource_client.get_text(&zone_action_request_path(zone_name, "export")).await
+    }
+
+    pub async fn remove_zone(&self, zone_name: &String) -> Result<(), RestClientError> {
+        self.pdns_resource_client.delete(&zone_request_path(zone_name)).await
+    }
+
+    pub async fn add_entry(&self, request: &AddEntryRequest) -> Result<(), RestClientError> {
+        self.pdns_resource_client.patch::<Rrsets>(&zone_request_path(&request.zone_name),
+                                                  &add_entry_body_provider(request)).await
+    }
+
+    pub async fn remove_entry(&self, request: &RemoveEntryRequest) -> Result<(), RestClientError> {
+        self.pdns_resource_client.patch::<Rrsets>(&zone_request_path(&request.zone_name),
+                                                  &remove_entry_body_provider(request)).await
+    }
+
+    pub async fn patch_rrsets(&self, zone_name: &String, rrsets: &Vec<Rrset>) -> Result<(), RestClientError> {
+        self.pdns_resource_client.patch::<Rrsets>(&zone_request_path(zone_name), &Rrsets::new(rrsets)).await
+    }
+}
+
+impl CreateZoneRequest {
+    /// Request with the default SOA timers, further settings are added with the `with_` methods
+    pub fn new(zone_name: &String, kind: &String, serial_policy: &SerialPolicy) -> CreateZoneRequest {
+        CreateZoneRequest {
+            zone_name: zone_name.clone(),
+            refresh: 3600,
+            retry: 1800,
+            expire: 604800,
+            neg_caching: 600,
+            kind: kind.clone(),
+            masters: Vec::new(),
+            nameservers: Vec::new(),
+            hostmaster: None,
+            account: None,
+            soa_edit: None,
+            soa_edit_api: None,
+            api_rectify: false,
+            dnssec: false,
+            nsec3param: None,
+            catalog: None,
+            serial_policy: serial_policy.clone(),
+        }
+    }
+
+    pub fn with_soa_timers(mut self, refresh: u32, retry: u32, expire: u32, neg_caching: u32) -> CreateZoneRequest {
+        self.refresh = refresh;
+        self.retry = retry;
+        self.expire = expire;
+        self.neg_caching = neg_caching;
+        self
+    }
+
+    pub fn with_masters(mut self, masters: &[String]) -> CreateZoneRequest {
+        self.masters = masters.to_vec();
+        self
+    }
+
+    pub fn with_nameservers(mut self, nameservers: &[String]) -> CreateZoneRequest {
+        self.nameservers = nameservers.to_vec();
+        self
+    }
+
+    /// Local part of the SOA contact address
+    pub fn with_hostmaster(mut self, hostmaster: &Option<String>) -> CreateZoneRequest {
+        self.hostmaster = hostmaster.clone();
+        self
+    }
+
+    /// PowerDNS account the zone is assigned to
+    pub fn with_account(mut self, account: &Option<String>) -> CreateZoneRequest {
+        self.account = account.clone();
+        self
+    }
+
+    pub fn with_soa_edit(mut self, soa_edit: &Option<String>, soa_edit_api: &Option<String>) -> CreateZoneRequest {
+        self.soa_edit = soa_edit.clone();
+        self.soa_edit_api = soa_edit_api.clone();
+        self
+    }
+
+    pub fn with_api_rectify(mut self, api_rectify: bool) -> CreateZoneRequest {
+        self.api_rectify = api_rectify;
+        self
+    }
+
+    pub fn with_dnssec(mut self, dnssec: bool, nsec3param: &Option<String>) -> CreateZoneRequest {
+        self.dnssec = dnssec;
+        self.nsec3param = nsec3param.clone();
+        self
+    }
+
+    pub fn with_catalog(mut self, catalog: &Option<String>) -> CreateZoneRequest {
+        self.catalog = catalog.clone();
+        self
+    }
+
+    /// Capabilities the server needs for the zone kind and the catalog membership
+    pub fn required_capabilities(&self) -> Vec<Capability> {
+        zone_capabilities(&Some(self.kind.clone()), &self.catalog)
+    }
+}
+
+impl UpdateZoneRequest {
+    /// Request without changes, the changed settings are added with the `with_` methods
+    pub fn new(zone_name: &String) -> UpdateZoneRequest {
+        UpdateZoneRequest {
+            zone_name: zone_name.clone(),
+            kind: None,
+            masters: None,
+            account: None,
+            soa_edit: None,
+            soa_edit_api: None,
+            api_rectify: None,
+            dnssec: None,
+            nsec3param: None,
+            catalog: None,
+        }
+    }
+
+    pub fn with_kind(mut self, kind: &Option<String>) -> UpdateZoneRequest {
+        self.kind = kind.clone();
+        self
+    }
+
+    pub fn with_masters(mut self, masters: &Option<Vec<String>>) -> UpdateZoneRequest {
+        self.masters = masters.clone();
+        self
+    }
+
+    pub fn with_account(mut self, account: &Option<String>) -> UpdateZoneRequest {
+        self.account = account.clone();
+        self
+    }
+
+    pub fn with_soa_edit(mut self, soa_edit: &Option<String>, soa_edit_api: &Option<String>) -> UpdateZoneRequest {
+        self.soa_edit = soa_edit.clone();
+        self.soa_edit_api = soa_edit_api.clone();
+        self
+    }
+
+    pub fn with_api_rectify(mut self, api_rectify: Option<bool>) -> UpdateZoneRequest {
+        self.api_rectify = api_rectify;
+        self
+    }
+
+    pub fn with_dnssec(mut self, dnssec: Option<bool>, nsec3param: &Option<String>) -> UpdateZoneRequest {
+        self.dnssec = dnssec;
+        self.nsec3param = nsec3param.clone();
+        self
+    }
+
+    pub fn with_catalog(mut self, catalog: &Option<String>) -> UpdateZoneRequest {
+        self.catalog = catalog.clone();
+        self
+    }
+
+    /// Capabilities the server needs for the changed zone kind and catalog membership
+    pub fn required_capabilities(&self) -> Vec<Capability> {
+        zone_capabilities(&self.kind, &self.catalog)
+    }
+}
+
+impl AddEntryRequest {
+    pub fn new(zone_name: &String, record_key: &String, record_type: &String,
+               record_values: &Vec<String>, time_to_live: u32, soa_rrset: &Option<Rrset>) -> AddEntryRequest {
+        AddEntryRequest {
+            zone_name: zone_name.clone(),
+            record_key: record_key.clone(),
+            record_type: record_type.clone(),
+            record_values: record_values.clone(),
+            time_to_live,
+            soa_rrset: soa_rrset.clone(),
+        }
+    }
+}
+
+impl Display for AddEntryRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zone_name: {}, record_key: {}, record_type: {}, values: {}, ttl {}",
+               self.zone_name.clone(), self.record_key.clone(), self.record_type.clone(),
+               self.record_values.clone().join(","), self.time_to_live)
+    }
+}
+
+impl RemoveEntryRequest {
+    pub fn new(zone_name: &String, record_key: &String, record_type: &String,
+               soa_rrset: &Option<Rrset>) -> RemoveEntryRequest {
+        RemoveEntryRequest {
+            zone_name: zone_name.clone(),
+            record_key: record_key.clone(),
+            record_type: record_type.clone(),
+            soa_rrset: soa_rrset.clone(),
+        }
+    }
+}
+
+impl Display for RemoveEntryRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "zone_name: {}, record_key: {}, record_type: {}",
+               self.zone_name.clone(), self.record_key.clone(), self.record_type.clone())
+    }
+}
+
+fn zones_request_path() -> String {
+    "servers/localhost/zones".to_string()
+}
+
+fn zone_request_path(zone_name: &String) -> String {
+    format!("servers/localhost/zones/{}", zone_name)
+}
+
+fn zone_action_request_path(zone_name: &String, action: &str) -> String {
+    format!("servers/localhost/zones/{}/{}", zone_name, action)
+}
+
+fn metadata_request_path(zone_name: &String, kind: &String) -> String {
+    format!("{}/{}", zone_action_request_path(zone_name, "metadata"), kind)
+}
+
+fn cryptokey_request_path(zone_name: &String, key_id: u32) -> String {
+    format!("{}/{}", zone_action_request_path(zone_name, "cryptokeys"), key_id)
+}
+
+fn create_zone_body_provider(request: &CreateZoneRequest) -> Result<NewZone, RestClientError> {
+    let mut rrsets: Vec<Rrset> = Vec::new();
+    let utc: DateTime<Utc> = Utc::now();
+    let serial = request.serial_policy.initial_serial(&utc);
+    let mut nameservers: Vec<String> = Vec::new();
+    let mut masters: Vec<String> = Vec::new();
+    let kind = map_zone_kind(&request.kind)?;
+
+    for value in request.nameservers.iter() {
+        nameservers.push(DnsName::absolute(value).to_string());
+    }
+    for value in request.masters.iter() {
+        masters.push(DnsName::absolute(value).to_string());
+    }
+
+    rrsets.push(Rrset::new(&DnsName::absolute(&request.zone_name).to_string(), RrsetType::Soa,
+                           &None,
+                           &Some(request.refresh),
+                           &vec![
+                               Record::new(&format!("{} {}.{} {} {} {} {} {}",
+                                                    DnsName::absolute(&request.zone_name),
+                                                    &request.hostmaster.clone().unwrap_or("root".to_string()),
+                                                    DnsName::absolute(&request.zone_name),
+                                                    serial,
+                                                    request.refresh,
+                                                    request.retry,
+                                                    request.expire,
+                                                    request.neg_caching),
+                                           false)
+                           ],
+                           &Vec::new()));
+
+    Ok(NewZone::new(&request.zone_name, kind, &rrsets, &masters, &nameservers)
+        .with_dnssec(request.dnssec, request.nsec3param.clone(), false)
+        .with_soa_edit(request.soa_edit.clone(), request.soa_edit_api.clone())
+        .with_api_rectify(request.api_rectify)
+        .with_account(request.account.clone())
+        .with_catalog(request.catalog.as_ref().map(|catalog| DnsName::absolute(catalog).to_string())))
+}
+
+fn update_zone_body_provider(request: &UpdateZoneRequest) -> Result<ZoneUpdate, RestClientError> {
+    let kind = match &request.kind {
+        Some(kind) => Some(map_zone_kind(kind)?),
+        None => None,
+    };
+    let masters = match &request.masters {
+        Some(masters) => Some(masters.clone()),
+        None => match &kind {
+            Some(kind) if !kind.is_secondary() => Some(Vec::new()),
+            _ => None,
+        },
+    };
+
+    Ok(ZoneUpdate::new()
+        .with_kind(kind)
+        .with_masters(masters)
+        .with_account(request.account.clone())
+        .with_soa_edit(request.soa_edit.clone(), request.soa_edit_api.clone())
+        .with_api_rectify(request.api_rectify)
+        .with_dnssec(request.dnssec, request.nsec3param.clone())
+        .with_catalog(request.catalog.as_ref().map(|catalog| match catalog.is_empty() {
+            true => catalog.clone(),
+            false => DnsName::absolute(catalog).to_string(),
+        })))
+}
+
+fn add_entry_body_provider(request: &AddEntryRequest) -> Rrsets {
+    let mut rrsets: Vec<Rrset> = Vec::new();
+    let mut records: Vec<Record> = Vec::new();
+
+    info!("create body for add-entry request: {}", request);
+
+    for record_value in request.record_values.iter() {
+        records.push(Record::new(record_value, false));
+    }
+
+    rrsets.push(Rrset::new(&DnsName::from_record_key(&request.record_key,
+                                                     &DnsName::absolute(&request.zone_name)).to_string(),
+                           map_record_type(&request.record_type).unwrap(),
+                           &Some(Changetype::Replace),
+                           &Some(request.time_to_live),
+                           &records,
+                           &Vec::new()));
+
+    if let Some(soa_rrset) = &request.soa_rrset {
+        rrsets.push(soa_rrset.clone());
+    }
+
+    Rrsets::new(&rrsets)
+}
+
+fn remove_entry_body_provider(request: &RemoveEntryRequest) -> Rrsets {
+    let mut rrsets: Vec<Rrset> = Vec::new();
+
+    info!("create body for remove-entry request: {}", request);
+
+    rrsets.push(Rrset::new(&DnsName::from_record_key(&request.record_key,
+                                                     &DnsName::absolute(&request.zone_name)).to_string(),
+                           map_record_type(&request.record_type).unwrap(),
+                           &Some(Changetype::Delete),
+                           &None,
+                           &Vec::new(),
+                           &Vec::new()));
+
+    if let Some(soa_rrset) = &request.soa_rrset {
+        rrsets.push(soa_rrset.clone());
+    }
+
+    Rrsets::new(&rrsets)
+}
+
+fn map_record_type(record_type: &String) -> Option<RrsetType> {
+    match record_type.as_str() {
+        "A" => Some(RrsetType::A),
+        "SOA" => Some(RrsetType::Soa),
+        "NS" => Some(RrsetType::Ns),
+        "PTR" => Some(RrsetType::Ptr),
+        "TXT" => Some(RrsetType::Txt),
+        "SRV" => Some(RrsetType::Srv),
+        "CNAME" => Some(RrsetType::Cname),
+        "AAAA" => Some(RrsetType::Aaaa),
+        "LUA" => Some(RrsetType::Lua),
+        _ => None,
+    }
+}
+
+fn zone_capabilities(kind: &Option<String>, catalog: &Option<String>) -> Vec<Capability> {
+    let mut capabilities: Vec<Capability> = kind.iter()
+        .filter_map(|kind| Capability::for_zone_kind(kind.as_str()))
+        .collect();
+
+    if catalog.is_some() {
+        capabilities.push(Capability::CatalogZones);
+    }
+
+    capabilities
+}
+
+fn map_zone_kind(zone_kind: &String) -> Result<ZoneKind, RestClientError> {
+    match zone_kind.as_str() {
+        "native" => Ok(ZoneKind::Native),
+        "master" => Ok(ZoneKind::Master),
+        "slave" => Ok(ZoneKind::Slave),
+        "producer" => Ok(ZoneKind::Producer),
+        "consumer" => Ok(ZoneKind::Consumer),
+        _ => Err(RestClientError::on_unspecified_error_message(&format!("Unknown zone kind: {}", zone_kind))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pdns::soa::SerialPolicy;
+    use crate::rest_client::zone_resource_client::{create_zone_body_provider, CreateZoneRequest, update_zone_body_provider,
+                                                   UpdateZoneRequest};
+
+    fn create_zone_request(kind: &str) -> CreateZoneRequest {
+        CreateZoneRequest::new(&"example.org".to_string(), &kind.to_string(), &SerialPolicy::None)
+            .with_nameservers(&vec!["ns1.example.org".to_string()])
+            .with_hostmaster(&Some("hostmaster".to_string()))
+            .with_account(&Some("customer-1".to_string()))
+    }
+
+    #[test]
+    fn should_separate_soa_contact_from_account() {
+        let body = serde_json::to_value(create_zone_body_provider(&create_zone_request("native")).unwrap()).unwrap();
+        let soa = body["rrsets"][0]["records"][0]["content"].as_str().unwrap();
+
+        assert!(soa.starts_with("example.org. hostmaster.example.org. "));
+        assert_eq!(body["account"], "customer-1");
+    }
+
+    fn update_zone_request(kind: &str, masters: &Option<Vec<String>>) -> UpdateZoneRequest {
+        UpdateZoneRequest::new(&"example.org".to_string())
+            .with_kind(&Some(kind.to_string()))
+            .with_masters(masters)
+    }
+
+    #[test]
+    fn should_clear_masters_of_primary_kinds() {
+        let body = serde_json::to_value(update_zone_body_provider(&update_zone_request("master", &None)).unwrap()).unwrap();
+
+        assert_eq!(body, serde_json::json!({"kind": "Master", "masters": []}));
+    }
+
+    #[test]
+    fn should_keep_masters_of_secondary_kinds() {
+        let masters = Some(vec!["192.0.2.1".to_string()]);
+        let body = serde_json::to_value(update_zone_body_provider(&update_zone_request("slave", &masters)).unwrap()).unwrap();
+
+        assert_eq!(body, serde_json::json!({"kind": "Slave", "masters": ["192.0.2.1"]}));
+    }
+
+    #[test]
+    fn should_fail_unknown_zone_kind() {
+        assert!(create_zone_body_provider(&create_zone_request("hidden")).is_err());
+    }
+}
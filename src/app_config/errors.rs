@@ -14,6 +14,7 @@
 use std::error::Error;
 use std::fmt;
 use std::fmt::Formatter;
+use std::path::PathBuf;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct AppConfigError {
@@ -25,9 +26,28 @@ pub enum AppConfigErrorKind {
     MalformedBaseUri { base_uri: String, parser_error: String },
     InvalidUriPart { base_uri: String, uri_part: UriPart },
     MalformedZoneName { zone_name: String, reason: String },
+    MalformedDomainName { domain_name: String, reason: String },
+    InvalidTlsConfiguration { reason: String },
+    InvalidProxyUri { proxy: String, reason: String },
+    InvalidTraceFile { path: String, reason: String },
+    MalformedTraceFormat { trace_format: String },
+    RecordKeyOutsideZone { record_key: String, zone_name: String },
     MalformedNumber { number: String },
+    MalformedBoolean { value: String },
     MalformedRecordType { record_type: String },
+    MalformedZoneKind { zone_kind: String },
+    MalformedSoaEdit { soa_edit: String },
+    MalformedSerialPolicy { serial_policy: String },
+    MalformedApiVersion { api_version: String },
+    MalformedStatisticName { statistic: String },
+    MalformedSettingName { setting: String },
+    MalformedServerAddress { address: String },
+    MalformedOutputFormat { output_format: String },
+    MalformedNetworkPrefix { network_prefix: String },
+    InconsistentZoneOptions { zone_kind: String, reason: String },
+    InconsistentRecordOptions { record_type: String, reason: String },
     MissingCommand,
+    MissingZoneName { command: String },
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -35,7 +55,8 @@ pub enum UriPart {
     Scheme { scheme: String },
     Username { user: String },
     Password { password: String },
-    Path { path: String },
+    Host { host: String },
+    SocketPath { path: String },
     Query { query: String },
     Fragment { fragment: String },
 }
@@ -62,24 +83,138 @@ impl AppConfigError {
         }
     }
 
+    pub fn on_invalid_tls_configuration(reason: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_invalid_tls_configuration(reason)
+        }
+    }
+
+    pub fn on_invalid_proxy_uri(proxy: &String, reason: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_invalid_proxy_uri(proxy, reason)
+        }
+    }
+
+    pub fn on_invalid_trace_file(path: &PathBuf, reason: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_invalid_trace_file(path, reason)
+        }
+    }
+
+    pub fn on_malformed_trace_format(trace_format: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_malformed_trace_format(trace_format)
+        }
+    }
+
+    pub fn on_malformed_domain_name(domain_name: &String, reason: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_malformed_domain_name(domain_name, reason)
+        }
+    }
+
     pub fn on_missing_command() -> AppConfigError {
         AppConfigError {
             kind: AppConfigErrorKind::MissingCommand
         }
     }
 
+    pub fn on_record_key_outside_zone(record_key: &String, zone_name: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_record_key_outside_zone(record_key, zone_name)
+        }
+    }
+
+    pub fn on_missing_zone_name(command: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_missing_zone_name(command)
+        }
+    }
+
     pub fn on_malformed_number(number: &String) -> AppConfigError {
         AppConfigError {
             kind: AppConfigErrorKind::on_malformed_number(number)
         }
     }
 
+    pub fn on_malformed_boolean(value: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_malformed_boolean(value)
+        }
+    }
+
     pub fn on_malformed_record_type(record_type: &String) -> AppConfigError {
         AppConfigError {
             kind: AppConfigErrorKind::on_malformed_record_type(record_type)
         }
     }
 
+    pub fn on_malformed_zone_kind(zone_kind: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_malformed_zone_kind(zone_kind)
+        }
+    }
+
+    pub fn on_malformed_soa_edit(soa_edit: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_malformed_soa_edit(soa_edit)
+        }
+    }
+
+    pub fn on_malformed_serial_policy(serial_policy: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_malformed_serial_policy(serial_policy)
+        }
+    }
+
+    pub fn on_malformed_api_version(api_version: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_malformed_api_version(api_version)
+        }
+    }
+
+    pub fn on_malformed_statistic_name(statistic: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_malformed_statistic_name(statistic)
+        }
+    }
+
+    pub fn on_malformed_setting_name(setting: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_malformed_setting_name(setting)
+        }
+    }
+
+    pub fn on_malformed_server_address(address: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_malformed_server_address(address)
+        }
+    }
+
+    pub fn on_malformed_output_format(output_format: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_malformed_output_format(output_format)
+        }
+    }
+
+    pub fn on_malformed_network_prefix(network_prefix: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_malformed_network_prefix(network_prefix)
+        }
+    }
+
+    pub fn on_inconsistent_zone_options(zone_kind: &String, reason: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_inconsistent_zone_options(zone_kind, reason)
+        }
+    }
+
+    pub fn on_inconsistent_record_options(record_type: &String, reason: &String) -> AppConfigError {
+        AppConfigError {
+            kind: AppConfigErrorKind::on_inconsistent_record_options(record_type, reason)
+        }
+    }
+
     fn __description(&self) -> String {
         match &self.kind {
             AppConfigErrorKind::MalformedBaseUri {
@@ -94,13 +229,76 @@ impl AppConfigError {
                 zone_name,
                 reason,
             } => format!("Malformed zone name {}: {}", zone_name, reason),
+            AppConfigErrorKind::InvalidTlsConfiguration {
+                reason
+            } => format!("Invalid TLS configuration: {}", reason),
+            AppConfigErrorKind::InvalidProxyUri {
+                proxy,
+                reason,
+            } => format!("Invalid proxy URI {}: {}", proxy, reason),
+            AppConfigErrorKind::InvalidTraceFile {
+                path,
+                reason,
+            } => format!("Cannot write HTTP trace to {}: {}", path, reason),
+            AppConfigErrorKind::MalformedTraceFormat {
+                trace_format
+            } => format!("Malformed HTTP trace format, expected jsonl or har: {}", trace_format),
+            AppConfigErrorKind::MalformedDomainName {
+                domain_name,
+                reason,
+            } => format!("Malformed domain name {}: {}", domain_name, reason),
+            AppConfigErrorKind::RecordKeyOutsideZone {
+                record_key,
+                zone_name,
+            } => format!("Record key {} is not part of zone {}", record_key, zone_name),
             AppConfigErrorKind::MissingCommand => format!("Command missing"),
+            AppConfigErrorKind::MissingZoneName {
+                command
+            } => format!("Zone name missing for command {}", command),
             AppConfigErrorKind::MalformedNumber {
                 number
             } => format!("Malformed number: {}", number),
+            AppConfigErrorKind::MalformedBoolean {
+                value
+            } => format!("Malformed boolean, expected true or false: {}", value),
             AppConfigErrorKind::MalformedRecordType {
                 record_type
             } => format!("Malformed record type: {}", record_type),
+            AppConfigErrorKind::MalformedZoneKind {
+                zone_kind
+            } => format!("Malformed zone kind: {}", zone_kind),
+            AppConfigErrorKind::MalformedSoaEdit {
+                soa_edit
+            } => format!("Malformed SOA-EDIT value: {}", soa_edit),
+            AppConfigErrorKind::MalformedSerialPolicy {
+                serial_policy
+            } => format!("Malformed serial policy: {}", serial_policy),
+            AppConfigErrorKind::MalformedApiVersion {
+                api_version
+            } => format!("Malformed API version: {}", api_version),
+            AppConfigErrorKind::MalformedStatisticName {
+                statistic
+            } => format!("Malformed statistic name: {}", statistic),
+            AppConfigErrorKind::MalformedSettingName {
+                setting
+            } => format!("Malformed setting name: {}", setting),
+            AppConfigErrorKind::MalformedServerAddress {
+                address
+            } => format!("Malformed server address: {}", address),
+            AppConfigErrorKind::MalformedOutputFormat {
+                output_format
+            } => format!("Malformed output format: {}", output_format),
+            AppConfigErrorKind::MalformedNetworkPrefix {
+                network_prefix
+            } => format!("Malformed network prefix: {}", network_prefix),
+            AppConfigErrorKind::InconsistentZoneOptions {
+                zone_kind,
+                reason,
+            } => format!("Inconsistent options for zone kind {}: {}", zone_kind, reason),
+            AppConfigErrorKind::InconsistentRecordOptions {
+                record_type,
+                reason,
+            } => format!("Inconsistent options for record type {}: {}", record_type, reason),
         }
     }
 }
@@ -133,17 +331,137 @@ impl AppConfigErrorKind {
         }
     }
 
+    fn on_invalid_tls_configuration(reason: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::InvalidTlsConfiguration {
+            reason: reason.clone(),
+        }
+    }
+
+    fn on_invalid_proxy_uri(proxy: &String, reason: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::InvalidProxyUri {
+            proxy: proxy.clone(),
+            reason: reason.clone(),
+        }
+    }
+
+    fn on_invalid_trace_file(path: &PathBuf, reason: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::InvalidTraceFile {
+            path: path.display().to_string(),
+            reason: reason.clone(),
+        }
+    }
+
+    fn on_malformed_trace_format(trace_format: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::MalformedTraceFormat {
+            trace_format: trace_format.clone(),
+        }
+    }
+
+    fn on_malformed_domain_name(domain_name: &String, reason: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::MalformedDomainName {
+            domain_name: domain_name.clone(),
+            reason: reason.clone(),
+        }
+    }
+
+    fn on_record_key_outside_zone(record_key: &String, zone_name: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::RecordKeyOutsideZone {
+            record_key: record_key.clone(),
+            zone_name: zone_name.clone(),
+        }
+    }
+
+    fn on_missing_zone_name(command: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::MissingZoneName {
+            command: command.clone(),
+        }
+    }
+
     fn on_malformed_number(number: &String) -> AppConfigErrorKind {
         AppConfigErrorKind::MalformedNumber {
             number: number.clone(),
         }
     }
 
+    fn on_malformed_boolean(value: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::MalformedBoolean {
+            value: value.clone(),
+        }
+    }
+
     fn on_malformed_record_type(record_type: &String) -> AppConfigErrorKind {
         AppConfigErrorKind::MalformedRecordType {
             record_type: record_type.clone(),
         }
     }
+
+    fn on_malformed_zone_kind(zone_kind: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::MalformedZoneKind {
+            zone_kind: zone_kind.clone(),
+        }
+    }
+
+    fn on_malformed_soa_edit(soa_edit: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::MalformedSoaEdit {
+            soa_edit: soa_edit.clone(),
+        }
+    }
+
+    fn on_malformed_serial_policy(serial_policy: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::MalformedSerialPolicy {
+            serial_policy: serial_policy.clone(),
+        }
+    }
+
+    fn on_malformed_api_version(api_version: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::MalformedApiVersion {
+            api_version: api_version.clone(),
+        }
+    }
+
+    fn on_malformed_statistic_name(statistic: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::MalformedStatisticName {
+            statistic: statistic.clone(),
+        }
+    }
+
+    fn on_malformed_setting_name(setting: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::MalformedSettingName {
+            setting: setting.clone(),
+        }
+    }
+
+    fn on_malformed_server_address(address: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::MalformedServerAddress {
+            address: address.clone(),
+        }
+    }
+
+    fn on_malformed_output_format(output_format: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::MalformedOutputFormat {
+            output_format: output_format.clone(),
+        }
+    }
+
+    fn on_malformed_network_prefix(network_prefix: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::MalformedNetworkPrefix {
+            network_prefix: network_prefix.clone(),
+        }
+    }
+
+    fn on_inconsistent_zone_options(zone_kind: &String, reason: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::InconsistentZoneOptions {
+            zone_kind: zone_kind.clone(),
+            reason: reason.clone(),
+        }
+    }
+
+    fn on_inconsistent_record_options(record_type: &String, reason: &String) -> AppConfigErrorKind {
+        AppConfigErrorKind::InconsistentRecordOptions {
+            record_type: record_type.clone(),
+            reason: reason.clone(),
+        }
+    }
 }
 
 impl UriPart {
@@ -152,7 +470,8 @@ impl UriPart {
             UriPart::Scheme { scheme } => format!("Invalid URI scheme '{}'", scheme),
             UriPart::Username { user } => format!("Non-Empty URI user name '{}'", user),
             UriPart::Password { password } => format!("Non-Empty URI password '{}'", password),
-            UriPart::Path { path } => format!("Non-empty URI path '{}'", path),
+            UriPart::Host { host } => format!("Non-empty Unix socket URI host '{}'", host),
+            UriPart::SocketPath { path } => format!("Invalid Unix socket path '{}'", path),
             UriPart::Query { query } => format!("Non-empty query '{}'", query),
             UriPart::Fragment { fragment } => format!("Non-empty fragment '{}'", fragment),
         }
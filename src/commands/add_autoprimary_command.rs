@@ -0,0 +1,69 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::autoprimary::Autoprimary;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::pdns::version::Capability;
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+
+pub struct AddAutoprimaryCommand {
+    context: Arc<CommandContext>,
+}
+
+impl AddAutoprimaryCommand {
+    pub fn new(context: &Arc<CommandContext>) -> AddAutoprimaryCommand {
+        AddAutoprimaryCommand {
+            context: context.clone(),
+        }
+    }
+
+    async fn execute_add_autoprimary(&self, autoprimary: &Autoprimary) -> Result<(), RestClientError> {
+        match self.context.server_client().add_autoprimary(autoprimary).await {
+            Ok(()) => {
+                info!("Received add autoprimary data event");
+
+                Ok(())
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for AddAutoprimaryCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::AddAutoprimary { ip, nameserver, account } = parameters {
+            info!("Executing command autoprimaries add, ip {}, nameserver {}", &ip, &nameserver);
+
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    self.context.server_client().require_capability(&server, Capability::Autoprimaries)?;
+                    self.execute_add_autoprimary(&Autoprimary::new(&ip, &nameserver, &account)).await
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
@@ -12,86 +12,85 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use chrono::Utc;
 use log::info;
+use pdns_api::pdns::dns_name::DnsName;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::pdns::soa::next_soa_rrset;
+use pdns_api::pdns::version::Capability;
+use pdns_api::pdns::zone::Rrset;
+use pdns_api::rest_client::errors::{RestClientError, RestClientErrorKind};
+use pdns_api::rest_client::zone_resource_client::AddEntryRequest;
 use reqwest::StatusCode;
-use tokio::sync::oneshot::channel;
 
 use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
 use crate::commands::command_handler::CommandExecutor;
-use crate::pdns::server::{DaemonType, Server};
-use crate::pdns::zone::Zone;
-use crate::rest_client::errors::{RestClientError, RestClientErrorKind};
-use crate::rest_client::pdns_resource_client::PnsServerResponse;
-use crate::rest_client::server_resource_client::{QueryServerRequestEvent, ServerResourceClient};
-use crate::rest_client::zone_resource_client::{AddEntryRequestEvent, QueryZoneRequestEvent, ZoneResourceClient};
+use crate::commands::flush_cache_command::FlushCacheCommand;
+use crate::commands::reverse_pointer_records::ReversePointerRecords;
 
 pub struct AddEntryCommand {
-    base_uri: String,
-    api_key: String,
+    context: Arc<CommandContext>,
     zone_name: String,
 }
 
 impl AddEntryCommand {
-    pub fn new(base_uri: &String, api_key: &String, zone_name: &String) -> AddEntryCommand {
+    pub fn new(context: &Arc<CommandContext>, zone_name: &String) -> AddEntryCommand {
         AddEntryCommand {
-            base_uri: base_uri.clone(),
-            api_key: api_key.clone(),
+            context: context.clone(),
             zone_name: zone_name.clone(),
         }
     }
 
-    async fn execute_get_zone(&self, record_key: &String, record_type: &String, record_value: &Vec<String>, time_to_live: u32) -> Result<(), RestClientError> {
-        let mut zone_resource_client = ZoneResourceClient::new(&self.base_uri, &self.api_key);
-        let (request_tx, request_rx) = channel::<QueryZoneRequestEvent>();
-        let (response_tx, response_rx) = channel::<PnsServerResponse<QueryZoneRequestEvent, Zone>>();
-
-        zone_resource_client.spawn_query_zone(request_rx, response_tx);
-
-        match request_tx.send(QueryZoneRequestEvent::new(&self.zone_name)) {
-            Ok(()) => match response_rx.await {
-                Ok(response_container) => match response_container.response() {
-                    Ok(zone) => {
-                        info!("Received zone data event: {}", zone);
-
-                        self.execute_add_entry(record_key, record_type, record_value, time_to_live).await
+    async fn execute_get_zone(&self, record_key: &String, record_type: &String, record_value: &Vec<String>, time_to_live: u32,
+                              with_ptr: bool) -> Result<Vec<String>, RestClientError> {
+        match self.context.zone_client().get_zone(&self.zone_name).await {
+            Ok(zone) => {
+                info!("Received zone data event: {}", zone);
+
+                let pointer_records = ReversePointerRecords::new(&self.context);
+                let reverse_names = match with_ptr {
+                    true => Some(pointer_records.resolve_reverse_zones(record_value).await?),
+                    false => None,
+                };
+
+                self.execute_add_entry(record_key, record_type, record_value, time_to_live,
+                                       &next_soa_rrset(&zone, self.context.serial_policy(), &Utc::now())).await?;
+                match reverse_names {
+                    Some(reverse_names) => {
+                        pointer_records
+                            .add_pointer_records(&DnsName::from_record_key(record_key, &DnsName::absolute(&self.zone_name)).to_string(),
+                                                 &reverse_names, time_to_live).await?;
+
+                        Ok(reverse_names.into_values().flatten().collect())
                     }
-                    Err(error) => match error.kind() {
-                        RestClientErrorKind::PowerDnsServerError { status_code, server_error: _ } if status_code == StatusCode::NOT_FOUND => {
-                            info!("Existing zone not found");
+                    None => Ok(Vec::new()),
+                }
+            }
+            Err(error) => match error.kind() {
+                RestClientErrorKind::PowerDnsServerError { status_code, server_error: _ } if status_code == StatusCode::NOT_FOUND => {
+                    info!("Existing zone not found");
 
-                            Ok(())
-                        }
-                        _ => Err(error.clone())
-                    }
-                },
-                Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+                    Ok(Vec::new())
+                }
+                _ => Err(error)
             }
-            Err(_) => Err(RestClientError::on_unspecified_error()),
         }
     }
 
-    async fn execute_add_entry(&self, record_key: &String, record_type: &String, record_value: &Vec<String>, time_to_live: u32) -> Result<(), RestClientError> {
-        let mut zone_resource_client = ZoneResourceClient::new(&self.base_uri, &self.api_key);
-        let (request_tx, request_rx) = channel::<AddEntryRequestEvent>();
-        let (response_tx, response_rx) = channel::<PnsServerResponse<AddEntryRequestEvent, ()>>();
-
-        zone_resource_client.spawn_add_entry(request_rx, response_tx);
-
-        match request_tx.send(AddEntryRequestEvent::new(&self.zone_name, record_key, record_type,
-                                                        record_value, time_to_live)) {
-            Ok(()) => match response_rx.await {
-                Ok(response_container) => match response_container.response() {
-                    Ok(()) => {
-                        info!("Received remove zone data event");
+    async fn execute_add_entry(&self, record_key: &String, record_type: &String, record_value: &Vec<String>, time_to_live: u32,
+                               soa_rrset: &Option<Rrset>) -> Result<(), RestClientError> {
+        match self.context.zone_client().add_entry(&AddEntryRequest::new(&self.zone_name, record_key, record_type,
+                                                   record_value, time_to_live, soa_rrset)).await {
+            Ok(()) => {
+                info!("Received remove zone data event");
 
-                        Ok(())
-                    }
-                    Err(error) => Err(error.clone()),
-                },
-                Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+                Ok(())
             }
-            Err(_) => Err(RestClientError::on_unspecified_error()),
+            Err(error) => Err(error),
         }
     }
 }
@@ -100,30 +99,31 @@ impl AddEntryCommand {
 impl CommandExecutor for AddEntryCommand {
     async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
         if let CommandParameters::AddEntry {
-            record_key, record_type, record_value, time_to_live,
+            record_key, record_type, record_value, time_to_live, with_ptr, flush,
         } = parameters {
-            info!("Executing command add-entry, zone {}", &self.zone_name);
+            info!("Executing command add-entry, zone {}, key {}", &self.zone_name,
+                  DnsName::from_record_key(&record_key, &DnsName::absolute(&self.zone_name))
+                      .relative_to(&DnsName::absolute(&self.zone_name)));
 
-            let mut server_resource_client = ServerResourceClient::new(&self.base_uri, &self.api_key);
-            let (request_tx, request_rx) = channel::<QueryServerRequestEvent>();
-            let (response_tx, response_rx) = channel::<PnsServerResponse<QueryServerRequestEvent, Server>>();
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
 
-            server_resource_client.spawn_query(request_rx, response_tx);
+                    if let Some(capability) = Capability::for_record_type(&record_type) {
+                        self.context.server_client().require_enabled_capability(&server, capability).await?;
+                    }
 
-            match request_tx.send(QueryServerRequestEvent::new()) {
-                Ok(()) => match response_rx.await {
-                    Ok(response_container) => match response_container.response() {
-                        Ok(server) if server.daemon_type() == DaemonType::Authoritative => {
-                            info!("Received Server data event: {}", server);
+                    match self.execute_get_zone(&record_key, &record_type, &record_value, time_to_live, with_ptr).await {
+                        Ok(mut names) if flush => {
+                            names.insert(0, DnsName::from_record_key(&record_key, &DnsName::absolute(&self.zone_name)).to_string());
 
-                            self.execute_get_zone(&record_key, &record_type, &record_value, time_to_live).await
+                            FlushCacheCommand::new(&self.context).flush_names(&names).await
                         }
-                        Ok(_) => Err(RestClientError::on_unspecified_error()),
-                        Err(error) => Err(error.clone()),
-                    },
-                    Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+                        Ok(_) => Ok(()),
+                        Err(error) => Err(error),
+                    }
                 }
-                Err(_) => Err(RestClientError::on_unspecified_error()),
+                Err(error) => Err(error),
             }
         } else {
             Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
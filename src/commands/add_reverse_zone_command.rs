@@ -0,0 +1,107 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::reverse::{parse_network_prefix, reverse_zone_names};
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::add_zone_command::AddZoneCommand;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+
+const MAX_REVERSE_ZONES: usize = 16;
+
+pub struct AddReverseZoneCommand {
+    context: Arc<CommandContext>,
+}
+
+impl AddReverseZoneCommand {
+    pub fn new(context: &Arc<CommandContext>) -> AddReverseZoneCommand {
+        AddReverseZoneCommand {
+            context: context.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for AddReverseZoneCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::AddReverseZone {
+            cidr, kind, masters, nameservers, hostmaster, account, force,
+        } = parameters {
+            info!("Executing command add-reverse-zone, network prefix {}", &cidr);
+
+            let zone_names = match parse_network_prefix(cidr.as_str()) {
+                Some((network, prefix_len)) => reverse_zone_names(&network, prefix_len),
+                None => return Err(RestClientError::on_unspecified_error_message(&format!("Malformed network prefix: {}", &cidr))),
+            };
+
+            verify_reverse_zone_count(&cidr, &zone_names, force)?;
+
+            for zone_name in zone_names.iter() {
+                info!("Adding reverse zone {} for network prefix {}", zone_name, &cidr);
+
+                AddZoneCommand::new(&self.context, zone_name)
+                    .execute_command(CommandParameters::AddZone {
+                        refresh: 3600,
+                        retry: 1800,
+                        expire: 604800,
+                        neg_caching: 600,
+                        kind: kind.clone(),
+                        masters: masters.clone(),
+                        nameservers: nameservers.clone(),
+                        hostmaster: hostmaster.clone(),
+                        account: account.clone(),
+                        soa_edit: None,
+                        soa_edit_api: None,
+                        api_rectify: false,
+                        dnssec: false,
+                        nsec3param: None,
+                        catalog: None,
+                    }).await?;
+            }
+
+            Ok(())
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
+
+fn verify_reverse_zone_count(cidr: &str, zone_names: &[String], force: bool) -> Result<(), RestClientError> {
+    match zone_names.len() {
+        count if count > MAX_REVERSE_ZONES && !force =>
+            Err(RestClientError::on_unspecified_error_message(
+                &format!("Network prefix {} is covered by {} reverse zones, use --force to create them", cidr, count))),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::add_reverse_zone_command::verify_reverse_zone_count;
+
+    #[test]
+    fn should_limit_reverse_zone_count() {
+        let cidr = "10.0.0.0/9";
+        let zone_names: Vec<String> = (0..128).map(|index| format!("{}.10.in-addr.arpa.", index)).collect();
+
+        assert!(verify_reverse_zone_count(cidr, &zone_names, false).is_err());
+        assert!(verify_reverse_zone_count(cidr, &zone_names, true).is_ok());
+        assert!(verify_reverse_zone_count(cidr, &zone_names[..16], false).is_ok())
+    }
+}
@@ -11,92 +11,79 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use log::info;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::rest_client::errors::{RestClientError, RestClientErrorKind};
+use pdns_api::rest_client::zone_resource_client::CreateZoneRequest;
 use reqwest::StatusCode;
-use tokio::sync::oneshot::channel;
 
 use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
 use crate::commands::command_handler::CommandExecutor;
-use crate::pdns::server::{DaemonType, Server};
-use crate::pdns::zone::Zone;
-use crate::rest_client::errors::{RestClientError, RestClientErrorKind};
-use crate::rest_client::pdns_resource_client::PnsServerResponse;
-use crate::rest_client::server_resource_client::{QueryServerRequestEvent, ServerResourceClient};
-use crate::rest_client::zone_resource_client::{CreateZoneRequestEvent, QueryZoneRequestEvent, ZoneResourceClient};
 
 pub struct AddZoneCommand {
-    base_uri: String,
-    api_key: String,
+    context: Arc<CommandContext>,
     zone_name: String,
 }
 
 impl AddZoneCommand {
-    pub fn new(base_uri: &String, api_key: &String, zone_name: &String) -> AddZoneCommand {
+    pub fn new(context: &Arc<CommandContext>, zone_name: &String) -> AddZoneCommand {
         AddZoneCommand {
-            base_uri: base_uri.clone(),
-            api_key: api_key.clone(),
+            context: context.clone(),
             zone_name: zone_name.clone(),
         }
     }
 
-    async fn execute_get_zone(&self, refresh: u32, retry: u32, expire: u32,
-                              neg_caching: u32, masters: &Vec<String>,
-                              nameservers: &Vec<String>, account: &String) -> Result<(), RestClientError> {
-        let mut zone_resource_client = ZoneResourceClient::new(&self.base_uri, &self.api_key);
-        let (request_tx, request_rx) = channel::<QueryZoneRequestEvent>();
-        let (response_tx, response_rx) = channel::<PnsServerResponse<QueryZoneRequestEvent, Zone>>();
-
-        zone_resource_client.spawn_query_zone(request_rx, response_tx);
-
-        match request_tx.send(QueryZoneRequestEvent::new(&self.zone_name)) {
-            Ok(()) => match response_rx.await {
-                Ok(response_container) => match response_container.response() {
-                    Ok(zone) => {
-                        info!("Received zone data event: {}", zone);
+    async fn execute_get_zone(&self, parameters: &CommandParameters) -> Result<(), RestClientError> {
+        match self.context.zone_client().get_zone(&self.zone_name).await {
+            Ok(zone) => {
+                info!("Received zone data event: {}", zone);
 
-                        Ok(())
-                    }
-                    Err(error) => match error.kind() {
-                        RestClientErrorKind::PowerDnsServerError { status_code, server_error: _ } if status_code == StatusCode::NOT_FOUND => {
-                            info!("Existing zone not found");
+                Ok(())
+            }
+            Err(error) => match error.kind() {
+                RestClientErrorKind::PowerDnsServerError { status_code, server_error: _ } if status_code == StatusCode::NOT_FOUND => {
+                    info!("Existing zone not found");
 
-                            self.execute_create_zone(refresh, retry, expire, neg_caching,
-                                                     masters, nameservers, account).await
-                        }
-                        _ => Err(error.clone())
-                    }
-                },
-                Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+                    self.execute_create_zone(parameters).await
+                }
+                _ => Err(error)
             }
-            Err(_) => Err(RestClientError::on_unspecified_error()),
         }
     }
 
-    async fn execute_create_zone(&self, refresh: u32, retry: u32, expire: u32,
-                                 neg_caching: u32, masters: &Vec<String>,
-                                 nameservers: &Vec<String>, account: &String) -> Result<(), RestClientError> {
-        let mut zone_resource_client = ZoneResourceClient::new(&self.base_uri, &self.api_key);
-        let (request_tx, request_rx) = channel::<CreateZoneRequestEvent>();
-        let (response_tx, response_rx) = channel::<PnsServerResponse<CreateZoneRequestEvent, Zone>>();
+    fn create_zone_request(&self, parameters: &CommandParameters) -> Result<CreateZoneRequest, RestClientError> {
+        match parameters {
+            CommandParameters::AddZone {
+                refresh, retry, expire, neg_caching, kind, masters, nameservers, hostmaster,
+                account, soa_edit, soa_edit_api, api_rectify, dnssec, nsec3param, catalog,
+            } => Ok(CreateZoneRequest::new(&self.zone_name, kind, self.context.serial_policy())
+                .with_soa_timers(*refresh, *retry, *expire, *neg_caching)
+                .with_masters(masters)
+                .with_nameservers(nameservers)
+                .with_hostmaster(hostmaster)
+                .with_account(account)
+                .with_soa_edit(soa_edit, soa_edit_api)
+                .with_api_rectify(*api_rectify)
+                .with_dnssec(*dnssec, nsec3param)
+                .with_catalog(catalog)),
+            _ => Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string())),
+        }
+    }
 
-        zone_resource_client.spawn_create_zone(request_rx, response_tx);
+    async fn execute_create_zone(&self, parameters: &CommandParameters) -> Result<(), RestClientError> {
+        let request_event = self.create_zone_request(parameters)?;
 
-        match request_tx.send(CreateZoneRequestEvent::new(&self.zone_name, refresh, retry,
-                                                          expire, neg_caching, masters,
-                                                          nameservers, account)) {
-            Ok(()) => match response_rx.await {
-                Ok(response_container) => match response_container.response() {
-                    Ok(zone) => {
-                        info!("Received create zone data event: {}", zone);
+        match self.context.zone_client().create_zone(&request_event).await {
+            Ok(zone) => {
+                info!("Received create zone data event: {}", zone);
 
-                        Ok(())
-                    }
-                    Err(error) => Err(error.clone()),
-                },
-                Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+                Ok(())
             }
-            Err(_) => Err(RestClientError::on_unspecified_error()),
+            Err(error) => Err(error),
         }
     }
 }
@@ -105,34 +92,19 @@ impl AddZoneCommand {
 impl CommandExecutor for AddZoneCommand {
     async fn execute_command(&self,
                              command: CommandParameters) -> Result<(), RestClientError> {
-        if let CommandParameters::AddZone {
-            refresh, retry, expire,
-            neg_caching, masters, nameservers, account
-        } = command {
+        if let CommandParameters::AddZone { .. } = command {
             info!("Executing command add-zone, zone {}", &self.zone_name);
 
-            let mut server_resource_client = ServerResourceClient::new(&self.base_uri, &self.api_key);
-            let (request_tx, request_rx) = channel::<QueryServerRequestEvent>();
-            let (response_tx, response_rx) = channel::<PnsServerResponse<QueryServerRequestEvent, Server>>();
-
-            server_resource_client.spawn_query(request_rx, response_tx);
-
-            match request_tx.send(QueryServerRequestEvent::new()) {
-                Ok(()) => match response_rx.await {
-                    Ok(response_container) => match response_container.response() {
-                        Ok(server) if server.daemon_type() == DaemonType::Authoritative => {
-                            info!("Received Server data event: {}", server);
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
 
-                            self.execute_get_zone(refresh, retry, expire,
-                                                  neg_caching, &masters, &nameservers,
-                                                  &account).await
-                        }
-                        Ok(_) => Err(RestClientError::on_unspecified_error()),
-                        Err(error) => Err(error.clone()),
-                    },
-                    Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+                    self.create_zone_request(&command)?.required_capabilities()
+                        .iter()
+                        .try_for_each(|capability| self.context.server_client().require_capability(&server, *capability))?;
+                    self.execute_get_zone(&command).await
                 }
-                Err(_) => Err(RestClientError::on_unspecified_error()),
+                Err(error) => Err(error),
             }
         } else {
             Err(RestClientError::on_unspecified_error())
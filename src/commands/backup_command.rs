@@ -0,0 +1,162 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use log::info;
+use pdns_api::pdns::backup::{backup_directory_name, BACKUP_MANIFEST_FILE, BackupEntry, BackupManifest, ZoneBackup};
+use pdns_api::pdns::cryptokey::Cryptokey;
+use pdns_api::pdns::dns_name::DnsName;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::rest_client::errors::RestClientError;
+use tokio::fs::{DirBuilder, OpenOptions};
+use tokio::io::AsyncWriteExt;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+use crate::commands::output::write_output;
+
+pub struct BackupCommand {
+    context: Arc<CommandContext>,
+}
+
+impl BackupCommand {
+    pub fn new(context: &Arc<CommandContext>) -> BackupCommand {
+        BackupCommand {
+            context: context.clone(),
+        }
+    }
+
+    /// Names of the zones to back up, all zones of the server without selection
+    async fn execute_list_zones(&self, selection: &Vec<String>) -> Result<Vec<String>, RestClientError> {
+        match self.context.zone_client().list_zones().await {
+            Ok(zones) => {
+                info!("Received zone data event for number of zones: {}", zones.len());
+
+                let names: Vec<String> = zones.iter().map(|zone| zone.name()).collect();
+                let selected: Vec<String> = selection.iter()
+                    .map(|name| DnsName::absolute(name).to_string())
+                    .collect();
+
+                match selected.iter().find(|name| !names.contains(name)) {
+                    Some(name) => Err(RestClientError::on_unspecified_error_message(&format!("Zone {} does not exist", name))),
+                    None => Ok(names.into_iter()
+                        .filter(|name| selected.is_empty() || selected.contains(name))
+                        .collect()),
+                }
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    pub(crate) async fn execute_backup_zone(&self, zone_name: &String) -> Result<ZoneBackup, RestClientError> {
+        let zone = self.context.zone_client().get_zone(zone_name).await?;
+        let metadata = self.context.zone_client().list_metadata(zone_name).await?;
+        let mut cryptokeys: Vec<Cryptokey> = Vec::new();
+
+        // the key listing omits the private keys, these are only part of the single key
+        for cryptokey in self.context.zone_client().list_cryptokeys(zone_name).await?.iter() {
+            cryptokeys.push(self.context.zone_client().get_cryptokey(zone_name, cryptokey.id()).await?);
+        }
+        info!("Received backup data event for zone {}: {} metadata entries, {} keys",
+            zone_name, metadata.len(), cryptokeys.len());
+
+        Ok(ZoneBackup::new(&zone, &metadata, &cryptokeys))
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for BackupCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::Backup { zones, output_dir } = parameters {
+            info!("Executing command backup, output directory {}", &output_dir);
+
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    let zone_names = self.execute_list_zones(&zones).await?;
+                    let created_at = Utc::now();
+                    let directory = Path::new(output_dir.as_str()).join(backup_directory_name(&created_at));
+                    let mut entries: Vec<BackupEntry> = Vec::new();
+
+                    create_directory(&directory.join("zones")).await?;
+                    for zone_name in zone_names.iter() {
+                        let entry = BackupEntry::new(zone_name);
+                        let content = serde_json::to_string_pretty(&self.execute_backup_zone(zone_name).await?)
+                            .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string()))?;
+
+                        write_file(&directory.join(entry.file()), &content).await?;
+                        entries.push(entry);
+                    }
+
+                    // the manifest comes last, a backup without manifest is incomplete
+                    let manifest = BackupManifest::new(&created_at, &self.context.server_client().base_uri(),
+                                                       &server.version(), &entries);
+                    let content = serde_json::to_string_pretty(&manifest)
+                        .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string()))?;
+
+                    write_file(&directory.join(BACKUP_MANIFEST_FILE), &content).await?;
+
+                    write_output(&format!("Backed up {} zones to {}\n", entries.len(), directory.display()), &None).await
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
+
+/// The archive holds private keys, its directories and files are only accessible by the owner
+pub(crate) async fn create_directory(path: &Path) -> Result<(), RestClientError> {
+    DirBuilder::new().recursive(true).mode(0o700).create(path).await
+        .map_err(|error| RestClientError::on_unspecified_error_message(&format!("{}: {}", path.display(), error)))
+}
+
+pub(crate) async fn write_file(path: &Path, content: &str) -> Result<(), RestClientError> {
+    let result = match OpenOptions::new().write(true).create(true).truncate(true).mode(0o600).open(path).await {
+        Ok(mut file) => match file.write_all(content.as_bytes()).await {
+            Ok(()) => file.flush().await,
+            Err(error) => Err(error),
+        },
+        Err(error) => Err(error),
+    };
+
+    result.map_err(|error| RestClientError::on_unspecified_error_message(&format!("{}: {}", path.display(), error)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use crate::commands::backup_command::{create_directory, write_file};
+
+    #[tokio::test]
+    async fn should_restrict_archive_permissions() {
+        let directory = std::env::temp_dir().join(format!("pdns-cli-backup-{}", std::process::id()));
+        let file = directory.join("zones").join("example.org.json");
+
+        create_directory(&directory.join("zones")).await.unwrap();
+        write_file(&file, "{}").await.unwrap();
+
+        assert_eq!(std::fs::metadata(&directory).unwrap().permissions().mode() & 0o777, 0o700);
+        assert_eq!(std::fs::metadata(directory.join("zones")).unwrap().permissions().mode() & 0o777, 0o700);
+        assert_eq!(std::fs::metadata(&file).unwrap().permissions().mode() & 0o777, 0o600);
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::catalog::catalog_members;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::pdns::version::Capability;
+use pdns_api::pdns::zone::ListZone;
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+use crate::commands::output::{render_columns, write_output};
+
+pub struct CatalogMembersCommand {
+    context: Arc<CommandContext>,
+    zone_name: String,
+}
+
+impl CatalogMembersCommand {
+    pub fn new(context: &Arc<CommandContext>, zone_name: &String) -> CatalogMembersCommand {
+        CatalogMembersCommand {
+            context: context.clone(),
+            zone_name: zone_name.clone(),
+        }
+    }
+
+    async fn execute_list_members(&self) -> Result<Vec<ListZone>, RestClientError> {
+        match self.context.zone_client().list_zones().await {
+            Ok(zones) => {
+                info!("Received zone data event for number of zones: {}", zones.len());
+
+                Ok(catalog_members(&self.zone_name, &zones))
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for CatalogMembersCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::CatalogMembers { format, output_file } = parameters {
+            info!("Executing command catalog members, zone {}", &self.zone_name);
+
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    self.context.server_client().require_capability(&server, Capability::CatalogZones)?;
+
+                    let members = self.execute_list_members().await?;
+                    let output = match format.as_str() {
+                        "json" => serde_json::to_string_pretty(&members)
+                            .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string()))?,
+                        _ => render_table(&members),
+                    };
+
+                    write_output(&output, &output_file).await
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
+
+fn render_table(members: &Vec<ListZone>) -> String {
+    let mut rows: Vec<Vec<String>> = vec![vec!["NAME".to_string(), "KIND".to_string()]];
+
+    rows.extend(members.iter()
+        .map(|member| vec![member.name(), member.kind().to_string()]));
+
+    render_columns(&rows)
+}
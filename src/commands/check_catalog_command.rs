@@ -0,0 +1,95 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::catalog::{catalog_members, check_catalog, CatalogIssue};
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::pdns::version::Capability;
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+use crate::commands::output::{render_columns, write_output};
+
+/// Reports the disagreements between a catalog zone and its member zones, fails if there are any
+pub struct CheckCatalogCommand {
+    context: Arc<CommandContext>,
+    zone_name: String,
+}
+
+impl CheckCatalogCommand {
+    pub fn new(context: &Arc<CommandContext>, zone_name: &String) -> CheckCatalogCommand {
+        CheckCatalogCommand {
+            context: context.clone(),
+            zone_name: zone_name.clone(),
+        }
+    }
+
+    async fn execute_check_catalog(&self) -> Result<(), RestClientError> {
+        let catalog_zone = self.context.zone_client().get_zone(&self.zone_name).await?;
+
+        info!("Received zone data event: {}", catalog_zone);
+
+        let zones = self.context.zone_client().list_zones().await?;
+
+        info!("Received zone data event for number of zones: {}", zones.len());
+
+        let members = catalog_members(&self.zone_name, &zones);
+        let issues = check_catalog(&catalog_zone, &members);
+
+        match issues.is_empty() {
+            true => write_output(&format!("Catalog {} is consistent with {} member zones\n",
+                                          catalog_zone.name(), members.len()), &None).await,
+            false => {
+                write_output(&render_table(&catalog_zone.name(), &issues), &None).await?;
+
+                Err(RestClientError::on_unspecified_error_message(
+                    &format!("Catalog {} has {} inconsistencies", catalog_zone.name(), issues.len())))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for CheckCatalogCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::CheckCatalog {} = parameters {
+            info!("Executing command catalog check, zone {}", &self.zone_name);
+
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    self.context.server_client().require_capability(&server, Capability::CatalogZones)?;
+                    self.execute_check_catalog().await
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
+
+fn render_table(catalog: &String, issues: &Vec<CatalogIssue>) -> String {
+    let mut rows: Vec<Vec<String>> = vec![vec!["ZONE".to_string(), "ISSUE".to_string()]];
+
+    rows.extend(issues.iter()
+        .map(|issue| vec![issue.zone().unwrap_or(catalog.clone()), issue.to_string()]));
+
+    render_columns(&rows)
+}
@@ -0,0 +1,67 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use pdns_api::pdns::soa::SerialPolicy;
+use pdns_api::rest_client::client_configuration::ClientConfiguration;
+use pdns_api::rest_client::pdns_resource_client::PowerDnsRestClient;
+use pdns_api::rest_client::recursor_resource_client::RecursorResourceClient;
+use pdns_api::rest_client::server_resource_client::ServerResourceClient;
+use pdns_api::rest_client::zone_resource_client::ZoneResourceClient;
+
+/// State shared by all command executors. Holds the single REST client, so all requests of
+/// a run reuse the same connection pool.
+pub struct CommandContext {
+    server_client: ServerResourceClient,
+    zone_client: ZoneResourceClient,
+    recursor_client: RecursorResourceClient,
+    serial_policy: SerialPolicy,
+    client_configuration: ClientConfiguration,
+}
+
+impl CommandContext {
+    pub fn new(base_uri: &String, api_key: &String, client_configuration: &ClientConfiguration,
+               serial_policy: &SerialPolicy) -> CommandContext {
+        let rest_client = Arc::new(PowerDnsRestClient::new(base_uri, api_key, client_configuration));
+
+        CommandContext {
+            server_client: ServerResourceClient::new(&rest_client),
+            zone_client: ZoneResourceClient::new(&rest_client),
+            recursor_client: RecursorResourceClient::new(&rest_client),
+            serial_policy: serial_policy.clone(),
+            client_configuration: client_configuration.clone(),
+        }
+    }
+
+    pub fn server_client(&self) -> &ServerResourceClient {
+        &self.server_client
+    }
+
+    pub fn zone_client(&self) -> &ZoneResourceClient {
+        &self.zone_client
+    }
+
+    pub fn recursor_client(&self) -> &RecursorResourceClient {
+        &self.recursor_client
+    }
+
+    pub fn serial_policy(&self) -> &SerialPolicy {
+        &self.serial_policy
+    }
+
+    /// Client for another PowerDNS instance, using the same transport settings
+    pub fn other_server_client(&self, base_uri: &String, api_key: &String) -> ServerResourceClient {
+        ServerResourceClient::new(&Arc::new(PowerDnsRestClient::new(base_uri, api_key, &self.client_configuration)))
+    }
+}
@@ -12,17 +12,42 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use async_trait::async_trait;
+use pdns_api::pdns::soa::SerialPolicy;
+use pdns_api::rest_client::client_configuration::ClientConfiguration;
+use pdns_api::rest_client::errors::RestClientError;
 
 use crate::app_config::cmd_line_parser::{Command, CommandKind, CommandParameters};
+use crate::commands::add_autoprimary_command::AddAutoprimaryCommand;
 use crate::commands::add_entry_command::AddEntryCommand;
+use crate::commands::add_forward_zone_command::AddForwardZoneCommand;
+use crate::commands::add_reverse_zone_command::AddReverseZoneCommand;
 use crate::commands::add_zone_command::AddZoneCommand;
+use crate::commands::axfr_retrieve_command::AxfrRetrieveCommand;
+use crate::commands::backup_command::BackupCommand;
+use crate::commands::catalog_members_command::CatalogMembersCommand;
+use crate::commands::check_catalog_command::CheckCatalogCommand;
+use crate::commands::command_context::CommandContext;
+use crate::commands::diff_config_command::DiffConfigCommand;
+use crate::commands::export_zone_command::ExportZoneCommand;
+use crate::commands::flush_cache_command::FlushCacheCommand;
+use crate::commands::list_autoprimaries_command::ListAutoprimariesCommand;
+use crate::commands::list_forward_zones_command::ListForwardZonesCommand;
 use crate::commands::list_zones_command::ListZonesCommand;
+use crate::commands::notify_zone_command::NotifyZoneCommand;
 use crate::commands::query_zone_command::QueryZoneCommand;
+use crate::commands::rectify_zone_command::RectifyZoneCommand;
+use crate::commands::remove_autoprimary_command::RemoveAutoprimaryCommand;
 use crate::commands::remove_entry_command::RemoveEntryCommand;
+use crate::commands::remove_forward_zone_command::RemoveForwardZoneCommand;
 use crate::commands::remove_zone_command::RemoveZoneCommand;
-use crate::rest_client::errors::RestClientError;
+use crate::commands::restore_command::RestoreCommand;
+use crate::commands::rpz_stats_command::RpzStatsCommand;
+use crate::commands::server_config_command::ServerConfigCommand;
+use crate::commands::stats_command::StatsCommand;
+use crate::commands::update_zone_command::UpdateZoneCommand;
 
 pub struct CommandHandler {
     executors: HashMap<CommandKind, Box<dyn CommandExecutor>>,
@@ -34,15 +59,37 @@ pub(crate) trait CommandExecutor {
 }
 
 impl CommandHandler {
-    pub fn new(base_uri: &String, api_key: &String, zone_name: &String) -> CommandHandler {
+    pub fn new(base_uri: &String, api_key: &String, client_configuration: &ClientConfiguration, zone_name: &String, serial_policy: &SerialPolicy) -> CommandHandler {
+        let context = Arc::new(CommandContext::new(base_uri, api_key, client_configuration, serial_policy));
         let mut executors: HashMap<CommandKind, Box<dyn CommandExecutor>> = HashMap::new();
 
-        executors.insert(CommandKind::AddZone, Box::new(AddZoneCommand::new(&base_uri, &api_key, zone_name)));
-        executors.insert(CommandKind::QueryZone, Box::new(QueryZoneCommand::new(&base_uri, &api_key, zone_name)));
-        executors.insert(CommandKind::ListZones, Box::new(ListZonesCommand::new(&base_uri, &api_key, zone_name)));
-        executors.insert(CommandKind::RemoveZone, Box::new(RemoveZoneCommand::new(&base_uri, &api_key, zone_name)));
-        executors.insert(CommandKind::AddEntry, Box::new(AddEntryCommand::new(&base_uri, &api_key, zone_name)));
-        executors.insert(CommandKind::RemoveEntry, Box::new(RemoveEntryCommand::new(&base_uri, &api_key, zone_name)));
+        executors.insert(CommandKind::AddZone, Box::new(AddZoneCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::AddReverseZone, Box::new(AddReverseZoneCommand::new(&context)));
+        executors.insert(CommandKind::QueryZone, Box::new(QueryZoneCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::ListZones, Box::new(ListZonesCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::UpdateZone, Box::new(UpdateZoneCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::RectifyZone, Box::new(RectifyZoneCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::NotifyZone, Box::new(NotifyZoneCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::AxfrRetrieve, Box::new(AxfrRetrieveCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::ExportZone, Box::new(ExportZoneCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::RemoveZone, Box::new(RemoveZoneCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::AddEntry, Box::new(AddEntryCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::RemoveEntry, Box::new(RemoveEntryCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::Stats, Box::new(StatsCommand::new(&context)));
+        executors.insert(CommandKind::ServerConfig, Box::new(ServerConfigCommand::new(&context)));
+        executors.insert(CommandKind::DiffConfig, Box::new(DiffConfigCommand::new(&context)));
+        executors.insert(CommandKind::FlushCache, Box::new(FlushCacheCommand::new(&context)));
+        executors.insert(CommandKind::ListForwardZones, Box::new(ListForwardZonesCommand::new(&context)));
+        executors.insert(CommandKind::AddForwardZone, Box::new(AddForwardZoneCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::RemoveForwardZone, Box::new(RemoveForwardZoneCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::RpzStats, Box::new(RpzStatsCommand::new(&context)));
+        executors.insert(CommandKind::CatalogMembers, Box::new(CatalogMembersCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::CheckCatalog, Box::new(CheckCatalogCommand::new(&context, zone_name)));
+        executors.insert(CommandKind::ListAutoprimaries, Box::new(ListAutoprimariesCommand::new(&context)));
+        executors.insert(CommandKind::AddAutoprimary, Box::new(AddAutoprimaryCommand::new(&context)));
+        executors.insert(CommandKind::RemoveAutoprimary, Box::new(RemoveAutoprimaryCommand::new(&context)));
+        executors.insert(CommandKind::Backup, Box::new(BackupCommand::new(&context)));
+        executors.insert(CommandKind::Restore, Box::new(RestoreCommand::new(&context)));
 
         CommandHandler {
             executors,
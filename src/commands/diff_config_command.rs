@@ -0,0 +1,165 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::config::{ConfigSetting, ConfigValue};
+use pdns_api::rest_client::errors::RestClientError;
+use serde_json::{json, Value};
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+use crate::commands::output::{render_columns, write_output};
+
+/// Placeholder for settings only one of the servers reports
+const MISSING_SETTING: &'static str = "(unset)";
+
+/// Compares the settings of the configured server with those of another server
+pub struct DiffConfigCommand {
+    context: Arc<CommandContext>,
+}
+
+/// Setting whose value differs between the two servers, None if a server lacks the setting
+#[derive(Clone, Debug, PartialEq)]
+struct ConfigDifference {
+    name: String,
+    server: Option<ConfigValue>,
+    other_server: Option<ConfigValue>,
+}
+
+impl DiffConfigCommand {
+    pub fn new(context: &Arc<CommandContext>) -> DiffConfigCommand {
+        DiffConfigCommand {
+            context: context.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for DiffConfigCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::DiffConfig { other_base_uri, other_api_key, settings, format, output_file } = parameters {
+            info!("Executing command diff-config against {}", &other_base_uri);
+
+            let config = self.context.server_client().get_config().await?;
+            let other_config = self.context.other_server_client(&other_base_uri, &other_api_key)
+                .get_config().await?;
+
+            info!("Received config data events for number of settings: {} and {}", config.len(), other_config.len());
+
+            let differences = config_differences(&config, &other_config, &settings);
+            let output = match format.as_str() {
+                "json" => serde_json::to_string_pretty(&render_json(&differences))
+                    .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string()))?,
+                _ => render_table(&differences),
+            };
+
+            write_output(&output, &output_file).await
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
+
+/// Settings differing between both servers ordered by name, restricted to the given names if any
+fn config_differences(config: &Vec<ConfigSetting>, other_config: &Vec<ConfigSetting>,
+                      settings: &Vec<String>) -> Vec<ConfigDifference> {
+    let mut values: BTreeMap<String, (Option<ConfigValue>, Option<ConfigValue>)> = BTreeMap::new();
+
+    for config_setting in config.iter() {
+        values.entry(config_setting.name()).or_insert((None, None)).0 = Some(config_setting.value());
+    }
+    for config_setting in other_config.iter() {
+        values.entry(config_setting.name()).or_insert((None, None)).1 = Some(config_setting.value());
+    }
+
+    values.into_iter()
+        .filter(|(name, _)| settings.is_empty() || settings.contains(name))
+        .filter(|(_, (value, other_value))| value != other_value)
+        .map(|(name, (server, other_server))| ConfigDifference { name, server, other_server })
+        .collect()
+}
+
+fn render_json(differences: &Vec<ConfigDifference>) -> Value {
+    Value::Array(differences.iter()
+        .map(|difference| json!({
+            "name": difference.name,
+            "server": difference.server,
+            "other_server": difference.other_server,
+        }))
+        .collect())
+}
+
+fn render_table(differences: &Vec<ConfigDifference>) -> String {
+    let mut rows: Vec<Vec<String>> = vec![vec!["NAME".to_string(), "SERVER".to_string(), "OTHER SERVER".to_string()]];
+
+    rows.extend(differences.iter()
+        .map(|difference| vec![
+            difference.name.clone(),
+            difference.server.as_ref().map(|value| value.to_string()).unwrap_or(MISSING_SETTING.to_string()),
+            difference.other_server.as_ref().map(|value| value.to_string()).unwrap_or(MISSING_SETTING.to_string()),
+        ]));
+
+    render_columns(&rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use pdns_api::pdns::config::{ConfigSetting, ConfigValue};
+
+    use crate::commands::diff_config_command::{config_differences, ConfigDifference};
+
+    fn setting(name: &str, value: &str) -> ConfigSetting {
+        ConfigSetting::new(&name.to_string(), &ConfigValue::Single(value.to_string()))
+    }
+
+    #[test]
+    fn should_report_changed_and_missing_settings() {
+        let config = vec![setting("allow-axfr-ips", "127.0.0.0/8"), setting("default-ttl", "3600"),
+                          setting("webserver", "yes")];
+        let other_config = vec![setting("allow-axfr-ips", "10.0.0.0/8"), setting("default-ttl", "3600"),
+                                setting("default-soa-content", "a.misconfigured.dns.server.invalid")];
+
+        assert_eq!(config_differences(&config, &other_config, &Vec::new()), vec![
+            ConfigDifference {
+                name: "allow-axfr-ips".to_string(),
+                server: Some(ConfigValue::Single("127.0.0.0/8".to_string())),
+                other_server: Some(ConfigValue::Single("10.0.0.0/8".to_string())),
+            },
+            ConfigDifference {
+                name: "default-soa-content".to_string(),
+                server: None,
+                other_server: Some(ConfigValue::Single("a.misconfigured.dns.server.invalid".to_string())),
+            },
+            ConfigDifference {
+                name: "webserver".to_string(),
+                server: Some(ConfigValue::Single("yes".to_string())),
+                other_server: None,
+            },
+        ])
+    }
+
+    #[test]
+    fn should_restrict_differences_to_given_settings() {
+        let config = vec![setting("allow-axfr-ips", "127.0.0.0/8"), setting("webserver", "yes")];
+        let other_config = vec![setting("allow-axfr-ips", "10.0.0.0/8")];
+        let differences = config_differences(&config, &other_config, &vec!["allow-axfr-ips".to_string()]);
+
+        assert_eq!(differences.len(), 1);
+        assert_eq!(differences[0].name, "allow-axfr-ips".to_string())
+    }
+}
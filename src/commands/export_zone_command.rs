@@ -0,0 +1,92 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::io::{stdout, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::pdns::version::Capability;
+use pdns_api::rest_client::errors::RestClientError;
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+
+pub struct ExportZoneCommand {
+    context: Arc<CommandContext>,
+    zone_name: String,
+}
+
+impl ExportZoneCommand {
+    pub fn new(context: &Arc<CommandContext>, zone_name: &String) -> ExportZoneCommand {
+        ExportZoneCommand {
+            context: context.clone(),
+            zone_name: zone_name.clone(),
+        }
+    }
+
+    async fn execute_export_zone(&self) -> Result<String, RestClientError> {
+        match self.context.zone_client().export_zone(&self.zone_name).await {
+            Ok(zone_file) => {
+                info!("Received export zone data event of {} bytes", zone_file.len());
+
+                Ok(zone_file.clone())
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for ExportZoneCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::ExportZone { output_file } = parameters {
+            info!("Executing command export-zone, zone {}", &self.zone_name);
+
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    self.context.server_client().require_capability(&server, Capability::ZoneExport)?;
+
+                    match self.execute_export_zone().await {
+                        Ok(zone_file) => match output_file {
+                            Some(path_name) => match File::create(Path::new(path_name.as_str())).await {
+                                Ok(mut file) => match file.write_all(zone_file.as_bytes()).await {
+                                    Ok(()) => file.flush().await
+                                        .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string())),
+                                    Err(error) => Err(RestClientError::on_unspecified_error_message(&error.to_string())),
+                                },
+                                Err(error) => Err(RestClientError::on_unspecified_error_message(&error.to_string())),
+                            },
+                            None => match stdout().write_all(zone_file.as_bytes()) {
+                                Ok(()) => stdout().flush()
+                                    .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string())),
+                                Err(error) => Err(RestClientError::on_unspecified_error_message(&error.to_string())),
+                            },
+                        },
+                        Err(error) => Err(error),
+                    }
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::cache::CacheFlushResult;
+use pdns_api::pdns::dns_name::DnsName;
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+use crate::commands::output::write_output;
+
+/// Flushes cache entries, on its own or after record changes so the new records are served immediately
+pub struct FlushCacheCommand {
+    context: Arc<CommandContext>,
+}
+
+impl FlushCacheCommand {
+    pub fn new(context: &Arc<CommandContext>) -> FlushCacheCommand {
+        FlushCacheCommand {
+            context: context.clone(),
+        }
+    }
+
+    /// Flush the cache entries of the domain and report the number of removed entries
+    pub(crate) async fn flush_cache(&self, domain: &String, subtree: bool, record_type: &Option<String>) -> Result<(), RestClientError> {
+        let domain = DnsName::absolute(domain).to_string();
+
+        match self.context.server_client().flush_cache(&domain, subtree, record_type).await {
+            Ok(result) => {
+                info!("Received cache flush data event: {}", result);
+
+                write_output(&flush_report(&result, &domain), &None).await
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Flush the cache entries of each changed name, e.g. a record and its pointer records
+    pub(crate) async fn flush_names(&self, names: &[String]) -> Result<(), RestClientError> {
+        for name in names.iter() {
+            self.flush_cache(name, false, &None).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for FlushCacheCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::FlushCache { domain, subtree, record_type } = parameters {
+            info!("Executing command flush-cache, domain {}", &domain);
+
+            self.flush_cache(&domain, subtree, &record_type).await
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
+
+fn flush_report(result: &CacheFlushResult, domain: &str) -> String {
+    format!("Flushed {} cache entries for {}\n", result.count(), domain)
+}
+
+#[cfg(test)]
+mod tests {
+    use pdns_api::pdns::cache::CacheFlushResult;
+
+    use crate::commands::flush_cache_command::flush_report;
+
+    #[test]
+    fn should_report_flushed_entry_count() {
+        let result = CacheFlushResult::new(3, &"Flushed cache.".to_string());
+
+        assert_eq!(flush_report(&result, "www.example.com."), "Flushed 3 cache entries for www.example.com.\n")
+    }
+}
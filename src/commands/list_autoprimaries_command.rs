@@ -0,0 +1,87 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::autoprimary::Autoprimary;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::pdns::version::Capability;
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+use crate::commands::output::{render_columns, write_output};
+
+pub struct ListAutoprimariesCommand {
+    context: Arc<CommandContext>,
+}
+
+impl ListAutoprimariesCommand {
+    pub fn new(context: &Arc<CommandContext>) -> ListAutoprimariesCommand {
+        ListAutoprimariesCommand {
+            context: context.clone(),
+        }
+    }
+
+    async fn execute_list_autoprimaries(&self) -> Result<Vec<Autoprimary>, RestClientError> {
+        match self.context.server_client().list_autoprimaries().await {
+            Ok(autoprimaries) => {
+                info!("Received autoprimary data event for number of autoprimaries: {}", autoprimaries.len());
+
+                Ok(autoprimaries)
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for ListAutoprimariesCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::ListAutoprimaries { format, output_file } = parameters {
+            info!("Executing command autoprimaries list");
+
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    self.context.server_client().require_capability(&server, Capability::Autoprimaries)?;
+
+                    let autoprimaries = self.execute_list_autoprimaries().await?;
+                    let output = match format.as_str() {
+                        "json" => serde_json::to_string_pretty(&autoprimaries)
+                            .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string()))?,
+                        _ => render_table(&autoprimaries),
+                    };
+
+                    write_output(&output, &output_file).await
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
+
+fn render_table(autoprimaries: &Vec<Autoprimary>) -> String {
+    let mut rows: Vec<Vec<String>> = vec![vec!["IP".to_string(), "NAMESERVER".to_string(), "ACCOUNT".to_string()]];
+
+    rows.extend(autoprimaries.iter()
+        .map(|autoprimary| vec![autoprimary.ip(), autoprimary.nameserver(), autoprimary.account()]));
+
+    render_columns(&rows)
+}
@@ -0,0 +1,86 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::recursor::{RecursorZone, RecursorZoneKind};
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+use crate::commands::output::{render_columns, write_output};
+
+pub struct ListForwardZonesCommand {
+    context: Arc<CommandContext>,
+}
+
+impl ListForwardZonesCommand {
+    pub fn new(context: &Arc<CommandContext>) -> ListForwardZonesCommand {
+        ListForwardZonesCommand {
+            context: context.clone(),
+        }
+    }
+
+    async fn execute_list_zones(&self) -> Result<Vec<RecursorZone>, RestClientError> {
+        match self.context.recursor_client().list_zones().await {
+            Ok(zones) => {
+                info!("Received recursor zone data event for number of zones: {}", zones.len());
+
+                Ok(zones.into_iter()
+                    .filter(|zone| zone.kind() == RecursorZoneKind::Forwarded)
+                    .collect())
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for ListForwardZonesCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::ListForwardZones { format, output_file } = parameters {
+            info!("Executing command forward-zones list");
+
+            match self.context.server_client().get_server_of_type(DaemonType::Recursor).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    let zones = self.execute_list_zones().await?;
+                    let output = match format.as_str() {
+                        "json" => serde_json::to_string_pretty(&zones)
+                            .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string()))?,
+                        _ => render_table(&zones),
+                    };
+
+                    write_output(&output, &output_file).await
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
+
+fn render_table(zones: &Vec<RecursorZone>) -> String {
+    let mut rows: Vec<Vec<String>> = vec![vec!["NAME".to_string(), "RECURSION DESIRED".to_string(), "SERVERS".to_string()]];
+
+    rows.extend(zones.iter()
+        .map(|zone| vec![zone.name(), zone.recursion_desired().to_string(), zone.servers().join(", ")]));
+
+    render_columns(&rows)
+}
@@ -11,71 +11,50 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use std::io::{stdout, Write};
-use std::path::Path;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 use log::info;
+use pdns_api::pdns::dns_name::to_unicode_name;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::pdns::zone::ListZone;
+use pdns_api::rest_client::errors::{RestClientError, RestClientErrorKind};
 use reqwest::StatusCode;
-use tokio::fs::File;
-use tokio::io::AsyncWriteExt;
-use tokio::sync::oneshot::channel;
 
 use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
 use crate::commands::command_handler::CommandExecutor;
-use crate::pdns::server::{DaemonType, Server};
-use crate::pdns::zone::{ListZone};
-use crate::rest_client::errors::{RestClientError, RestClientErrorKind};
-use crate::rest_client::pdns_resource_client::PnsServerResponse;
-use crate::rest_client::server_resource_client::{QueryServerRequestEvent, ServerResourceClient};
-use crate::rest_client::zone_resource_client::{ListZonesRequestEvent, ZoneResourceClient};
+use crate::commands::output::{add_unicode_name, render_columns, write_output};
 
 pub struct ListZonesCommand {
-    base_uri: String,
-    api_key: String,
+    context: Arc<CommandContext>,
     #[allow(dead_code)]
     zone_name: String,
 }
 
 impl ListZonesCommand {
-    pub fn new(base_uri: &String, api_key: &String, zone_name: &String) -> ListZonesCommand {
+    pub fn new(context: &Arc<CommandContext>, zone_name: &String) -> ListZonesCommand {
         ListZonesCommand {
-            base_uri: base_uri.clone(),
-            api_key: api_key.clone(),
+            context: context.clone(),
             zone_name: zone_name.clone(),
         }
     }
 
-    async fn execute_list_zones(&self) -> Result<String, RestClientError> {
-        let mut zone_resource_client = ZoneResourceClient::new(&self.base_uri, &self.api_key);
-        let (request_tx, request_rx) = channel::<ListZonesRequestEvent>();
-        let (response_tx, response_rx) = channel::<PnsServerResponse<ListZonesRequestEvent, Vec<ListZone>>>();
-
-        zone_resource_client.spawn_list_zones(request_rx, response_tx);
-
-        match request_tx.send(ListZonesRequestEvent::new()) {
-            Ok(()) => match response_rx.await {
-                Ok(response_container) => match response_container.response() {
-                    Ok(zones) => {
-                        info!("Received zone data event for number of zones: {}", zones.len());
-
-                        match serde_json::to_string_pretty(zones) {
-                            Ok(json) => Ok(json.clone()),
-                            Err(_) => Err(RestClientError::on_unspecified_error()),
-                        }
-                    }
-                    Err(error) => match error.kind() {
-                        RestClientErrorKind::PowerDnsServerError { status_code, server_error: _ } if status_code == StatusCode::NOT_FOUND => {
-                            info!("Existing zone not found");
-
-                            Err(error.clone())
-                        }
-                        _ => Err(error.clone())
-                    }
-                },
-                Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+    async fn execute_list_zones(&self) -> Result<Vec<ListZone>, RestClientError> {
+        match self.context.zone_client().list_zones().await {
+            Ok(zones) => {
+                info!("Received zone data event for number of zones: {}", zones.len());
+
+                Ok(zones)
+            }
+            Err(error) => match error.kind() {
+                RestClientErrorKind::PowerDnsServerError { status_code, server_error: _ } if status_code == StatusCode::NOT_FOUND => {
+                    info!("Existing zone not found");
+
+                    Err(error)
+                }
+                _ => Err(error)
             }
-            Err(_) => Err(RestClientError::on_unspecified_error()),
         }
     }
 }
@@ -83,47 +62,73 @@ impl ListZonesCommand {
 #[async_trait]
 impl CommandExecutor for ListZonesCommand {
     async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
-        if let CommandParameters::ListZone { output_file } = parameters {
+        if let CommandParameters::ListZone { format, output_file } = parameters {
             info!("Executing command list-zones");
 
-            let mut server_resource_client = ServerResourceClient::new(&self.base_uri, &self.api_key);
-            let (request_tx, request_rx) = channel::<QueryServerRequestEvent>();
-            let (response_tx, response_rx) = channel::<PnsServerResponse<QueryServerRequestEvent, Server>>();
-
-            server_resource_client.spawn_query(request_rx, response_tx);
-
-            match request_tx.send(QueryServerRequestEvent::new()) {
-                Ok(()) => match response_rx.await {
-                    Ok(response_container) => match response_container.response() {
-                        Ok(server) if server.daemon_type() == DaemonType::Authoritative => {
-                            info!("Received Server data event: {}", server);
-
-                            match self.execute_list_zones().await {
-                                Ok(json) => match output_file {
-                                    Some(path_name) => match File::create(Path::new(path_name.as_str())).await {
-                                        Ok(mut file) => match file.write(json.as_bytes()).await {
-                                            Ok(_) => Ok(()),
-                                            Err(error) => Err(RestClientError::on_unspecified_error_message(&error.to_string())),
-                                        },
-                                        Err(error) => Err(RestClientError::on_unspecified_error_message(&error.to_string())),
-                                    },
-                                    None => match stdout().write(json.as_bytes()) {
-                                        Ok(_) => Ok(()),
-                                        Err(error) => Err(RestClientError::on_unspecified_error_message(&error.to_string())),
-                                    },
-                                },
-                                Err(error) => Err(error),
-                            }
-                        }
-                        Ok(_) => Err(RestClientError::on_unspecified_error()),
-                        Err(error) => Err(error.clone()),
-                    },
-                    Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    let zones = self.execute_list_zones().await?;
+                    let output = match format.as_str() {
+                        "table" => render_table(&zones),
+                        _ => render_json(&zones)?,
+                    };
+
+                    write_output(&output, &output_file).await
                 }
-                Err(_) => Err(RestClientError::on_unspecified_error()),
+                Err(error) => Err(error),
             }
         } else {
             Err(RestClientError::on_unspecified_error())
         }
     }
-}
\ No newline at end of file
+}
+
+fn render_json(zones: &[ListZone]) -> Result<String, RestClientError> {
+    let mut json = serde_json::to_value(zones)
+        .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string()))?;
+
+    if let Some(zones) = json.as_array_mut() {
+        zones.iter_mut().for_each(add_unicode_name);
+    }
+
+    serde_json::to_string_pretty(&json)
+        .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string()))
+}
+
+fn render_table(zones: &[ListZone]) -> String {
+    let mut rows: Vec<Vec<String>> = vec![vec!["NAME".to_string(), "UNICODE NAME".to_string(), "KIND".to_string(),
+                                               "SERIAL".to_string(), "CATALOG".to_string()]];
+
+    rows.extend(zones.iter()
+        .map(|zone| vec![zone.name(), to_unicode_name(&zone.name()), zone.kind().to_string(),
+                         zone.serial().to_string(), zone.catalog().unwrap_or_default()]));
+
+    render_columns(&rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use pdns_api::pdns::zone::ListZone;
+
+    use crate::commands::list_zones_command::{render_json, render_table};
+
+    fn zones() -> Vec<ListZone> {
+        serde_json::from_value(serde_json::json!([
+            {"id": "xn--bcher-kva.de.", "name": "xn--bcher-kva.de.", "url": "/", "kind": "Native", "serial": 2021110101,
+             "edited_serial": 2021110101, "notified_serial": 0, "last_check": 0, "masters": [], "dnssec": false,
+             "account": ""}
+        ])).unwrap()
+    }
+
+    #[test]
+    fn should_list_unicode_zone_names() {
+        let json: serde_json::Value = serde_json::from_str(&render_json(&zones()).unwrap()).unwrap();
+
+        assert_eq!(json[0]["unicode_name"], "bücher.de.");
+        assert_eq!(render_table(&zones()),
+                   "NAME               UNICODE NAME  KIND    SERIAL      CATALOG\n\
+                    xn--bcher-kva.de.  bücher.de.    Native  2021110101  \n")
+    }
+}
@@ -18,3 +18,27 @@ mod remove_zone_command;
 mod add_entry_command;
 mod remove_entry_command;
 mod list_zones_command;
+mod update_zone_command;
+mod rectify_zone_command;
+mod notify_zone_command;
+mod axfr_retrieve_command;
+mod export_zone_command;
+mod reverse_pointer_records;
+mod add_reverse_zone_command;
+mod command_context;
+mod stats_command;
+mod output;
+mod server_config_command;
+mod diff_config_command;
+mod flush_cache_command;
+mod list_forward_zones_command;
+mod add_forward_zone_command;
+mod remove_forward_zone_command;
+mod rpz_stats_command;
+mod catalog_members_command;
+mod check_catalog_command;
+mod list_autoprimaries_command;
+mod add_autoprimary_command;
+mod remove_autoprimary_command;
+mod backup_command;
+mod restore_command;
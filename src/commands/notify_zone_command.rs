@@ -0,0 +1,79 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+
+pub struct NotifyZoneCommand {
+    context: Arc<CommandContext>,
+    zone_name: String,
+}
+
+impl NotifyZoneCommand {
+    pub fn new(context: &Arc<CommandContext>, zone_name: &String) -> NotifyZoneCommand {
+        NotifyZoneCommand {
+            context: context.clone(),
+            zone_name: zone_name.clone(),
+        }
+    }
+
+    async fn execute_get_zone(&self) -> Result<(), RestClientError> {
+        match self.context.zone_client().get_zone(&self.zone_name).await {
+            Ok(zone) => {
+                info!("Received zone data event: {}", zone);
+
+                self.execute_notify_zone().await
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn execute_notify_zone(&self) -> Result<(), RestClientError> {
+        match self.context.zone_client().notify_zone(&self.zone_name).await {
+            Ok(result) => {
+                info!("Received notify zone data event: {}", result.result());
+
+                Ok(())
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for NotifyZoneCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::NotifyZone {} = parameters {
+            info!("Executing command notify-zone, zone {}", &self.zone_name);
+
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    self.execute_get_zone().await
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
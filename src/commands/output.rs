@@ -0,0 +1,99 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::io::{stdout, Write};
+use std::path::Path;
+
+use pdns_api::pdns::dns_name::to_unicode_name;
+use pdns_api::rest_client::errors::RestClientError;
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+
+/// Left aligned columns separated by two spaces, the first row holds the headers
+pub(crate) fn render_columns(rows: &Vec<Vec<String>>) -> String {
+    let column_count = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let widths: Vec<usize> = (0..column_count)
+        .map(|column| rows.iter()
+            .filter_map(|row| row.get(column))
+            .map(|cell| cell.chars().count())
+            .max()
+            .unwrap_or(0))
+        .collect();
+    let mut table = String::new();
+
+    for row in rows.iter() {
+        let cells: Vec<String> = row.iter()
+            .enumerate()
+            .map(|(column, cell)| match column + 1 < row.len() {
+                true => format!("{:<width$}", cell, width = widths[column]),
+                false => cell.clone(),
+            })
+            .collect();
+
+        table.push_str(cells.join("  ").as_str());
+        table.push('\n');
+    }
+
+    table
+}
+
+/// Add the Unicode form of the domain name in 'name' as 'unicode_name' to a JSON object
+pub(crate) fn add_unicode_name(value: &mut serde_json::Value) {
+    if let Some(name) = value.get("name").and_then(|name| name.as_str()).map(to_unicode_name) {
+        value["unicode_name"] = serde_json::Value::String(name);
+    }
+}
+
+/// Write the command output to the given file or to stdout
+pub(crate) async fn write_output(output: &String, output_file: &Option<String>) -> Result<(), RestClientError> {
+    match output_file {
+        Some(path_name) => match File::create(Path::new(path_name.as_str())).await {
+            Ok(mut file) => match file.write_all(output.as_bytes()).await {
+                Ok(()) => file.flush().await
+                    .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string())),
+                Err(error) => Err(RestClientError::on_unspecified_error_message(&error.to_string())),
+            },
+            Err(error) => Err(RestClientError::on_unspecified_error_message(&error.to_string())),
+        },
+        None => match stdout().write_all(output.as_bytes()) {
+            Ok(()) => stdout().flush()
+                .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string())),
+            Err(error) => Err(RestClientError::on_unspecified_error_message(&error.to_string())),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::output::{add_unicode_name, write_output};
+
+    #[test]
+    fn should_add_unicode_name() {
+        let mut zone = serde_json::json!({"name": "xn--bcher-kva.de.", "kind": "Native"});
+
+        add_unicode_name(&mut zone);
+
+        assert_eq!(zone, serde_json::json!({"name": "xn--bcher-kva.de.", "kind": "Native", "unicode_name": "bücher.de."}));
+    }
+
+    #[tokio::test]
+    async fn should_write_complete_output_file() {
+        let path = std::env::temp_dir().join(format!("pdns-cli-output-{}.txt", std::process::id()));
+        let output = "example.com.  3600  IN  A  192.0.2.1\n".repeat(4096);
+
+        write_output(&output, &Some(path.to_string_lossy().to_string())).await.unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), output);
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -11,70 +11,49 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use log::info;
-use reqwest::StatusCode;
-use tokio::fs::File;
-use tokio::sync::oneshot::channel;
-use std::io::{stdout, Write};
-use std::path::Path;
+use std::sync::Arc;
 
 use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::dns_name::to_unicode_name;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::pdns::zone::Zone;
+use pdns_api::rest_client::errors::{RestClientError, RestClientErrorKind};
+use reqwest::StatusCode;
 
 use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
 use crate::commands::command_handler::CommandExecutor;
-use crate::pdns::server::{DaemonType, Server};
-use crate::pdns::zone::Zone;
-use crate::rest_client::errors::{RestClientError, RestClientErrorKind};
-use crate::rest_client::pdns_resource_client::PnsServerResponse;
-use crate::rest_client::server_resource_client::{QueryServerRequestEvent, ServerResourceClient};
-use crate::rest_client::zone_resource_client::{QueryZoneRequestEvent, ZoneResourceClient};
-use tokio::io::AsyncWriteExt;
+use crate::commands::output::{add_unicode_name, render_columns, write_output};
 
 pub struct QueryZoneCommand {
-    base_uri: String,
-    api_key: String,
+    context: Arc<CommandContext>,
     zone_name: String,
 }
 
 impl QueryZoneCommand {
-    pub fn new(base_uri: &String, api_key: &String, zone_name: &String) -> QueryZoneCommand {
+    pub fn new(context: &Arc<CommandContext>, zone_name: &String) -> QueryZoneCommand {
         QueryZoneCommand {
-            base_uri: base_uri.clone(),
-            api_key: api_key.clone(),
+            context: context.clone(),
             zone_name: zone_name.clone(),
         }
     }
 
-    async fn execute_get_zone(&self) -> Result<String, RestClientError> {
-        let mut zone_resource_client = ZoneResourceClient::new(&self.base_uri, &self.api_key);
-        let (request_tx, request_rx) = channel::<QueryZoneRequestEvent>();
-        let (response_tx, response_rx) = channel::<PnsServerResponse<QueryZoneRequestEvent, Zone>>();
-
-        zone_resource_client.spawn_query_zone(request_rx, response_tx);
-
-        match request_tx.send(QueryZoneRequestEvent::new(&self.zone_name)) {
-            Ok(()) => match response_rx.await {
-                Ok(response_container) => match response_container.response() {
-                    Ok(zone) => {
-                        info!("Received zone data event: {}", zone);
-
-                        match serde_json::to_string_pretty(zone) {
-                            Ok(json) => Ok(json.clone()),
-                            Err(_) => Err(RestClientError::on_unspecified_error()),
-                        }
-                    }
-                    Err(error) => match error.kind() {
-                        RestClientErrorKind::PowerDnsServerError { status_code, server_error: _ } if status_code == StatusCode::NOT_FOUND => {
-                            info!("Existing zone not found");
-
-                            Err(error.clone())
-                        }
-                        _ => Err(error.clone())
-                    }
-                },
-                Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+    async fn execute_get_zone(&self) -> Result<Zone, RestClientError> {
+        match self.context.zone_client().get_zone(&self.zone_name).await {
+            Ok(zone) => {
+                info!("Received zone data event: {}", zone);
+
+                Ok(zone)
+            }
+            Err(error) => match error.kind() {
+                RestClientErrorKind::PowerDnsServerError { status_code, server_error: _ } if status_code == StatusCode::NOT_FOUND => {
+                    info!("Existing zone not found");
+
+                    Err(error)
+                }
+                _ => Err(error)
             }
-            Err(_) => Err(RestClientError::on_unspecified_error()),
         }
     }
 }
@@ -82,47 +61,85 @@ impl QueryZoneCommand {
 #[async_trait]
 impl CommandExecutor for QueryZoneCommand {
     async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
-        if let CommandParameters::QueryZone { output_file } = parameters {
+        if let CommandParameters::QueryZone { format, output_file } = parameters {
             info!("Executing command query-zone, zone {}", &self.zone_name);
 
-            let mut server_resource_client = ServerResourceClient::new(&self.base_uri, &self.api_key);
-            let (request_tx, request_rx) = channel::<QueryServerRequestEvent>();
-            let (response_tx, response_rx) = channel::<PnsServerResponse<QueryServerRequestEvent, Server>>();
-
-            server_resource_client.spawn_query(request_rx, response_tx);
-
-            match request_tx.send(QueryServerRequestEvent::new()) {
-                Ok(()) => match response_rx.await {
-                    Ok(response_container) => match response_container.response() {
-                        Ok(server) if server.daemon_type() == DaemonType::Authoritative => {
-                            info!("Received Server data event: {}", server);
-
-                            match self.execute_get_zone().await {
-                                Ok(json) => match output_file {
-                                    Some(path_name) => match File::create(Path::new(path_name.as_str())).await {
-                                        Ok(mut file) => match file.write(json.as_bytes()).await {
-                                            Ok(_) => Ok(()),
-                                            Err(error) => Err(RestClientError::on_unspecified_error_message(&error.to_string())),
-                                        },
-                                        Err(error) => Err(RestClientError::on_unspecified_error_message(&error.to_string())),
-                                    },
-                                    None => match stdout().write(json.as_bytes()) {
-                                        Ok(_) => Ok(()),
-                                        Err(error) => Err(RestClientError::on_unspecified_error_message(&error.to_string())),
-                                    },
-                                },
-                                Err(error) => Err(error),
-                            }
-                        }
-                        Ok(_) => Err(RestClientError::on_unspecified_error()),
-                        Err(error) => Err(error.clone()),
-                    },
-                    Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    let zone = self.execute_get_zone().await?;
+                    let output = match format.as_str() {
+                        "table" => render_table(&zone),
+                        _ => render_json(&zone)?,
+                    };
+
+                    write_output(&output, &output_file).await
                 }
-                Err(_) => Err(RestClientError::on_unspecified_error()),
+                Err(error) => Err(error),
             }
         } else {
             Err(RestClientError::on_unspecified_error())
         }
     }
-}
\ No newline at end of file
+}
+
+/// Zone JSON with the Unicode names of the zone and its rrsets
+fn render_json(zone: &Zone) -> Result<String, RestClientError> {
+    let mut json = serde_json::to_value(zone)
+        .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string()))?;
+
+    add_unicode_name(&mut json);
+    if let Some(rrsets) = json.get_mut("rrsets").and_then(|rrsets| rrsets.as_array_mut()) {
+        rrsets.iter_mut().for_each(add_unicode_name);
+    }
+
+    serde_json::to_string_pretty(&json)
+        .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string()))
+}
+
+/// One row per record of the zone
+fn render_table(zone: &Zone) -> String {
+    let mut rows: Vec<Vec<String>> = vec![vec!["NAME".to_string(), "UNICODE NAME".to_string(), "TTL".to_string(),
+                                               "TYPE".to_string(), "CONTENT".to_string()]];
+
+    rows.extend(zone.rrsets().iter()
+        .flat_map(|rrset| rrset.records().iter()
+            .map(|record| vec![rrset.name(), to_unicode_name(&rrset.name()),
+                               rrset.ttl().map(|ttl| ttl.to_string()).unwrap_or_default(),
+                               rrset.type_id().to_string(), record.content()])
+            .collect::<Vec<Vec<String>>>()));
+
+    render_columns(&rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use pdns_api::pdns::zone::Zone;
+
+    use crate::commands::query_zone_command::{render_json, render_table};
+
+    fn zone() -> Zone {
+        serde_json::from_value(serde_json::json!({
+            "id": "xn--bcher-kva.de.", "name": "xn--bcher-kva.de.", "type": "Zone", "url": "/", "kind": "Native",
+            "rrsets": [
+                {"name": "www.xn--bcher-kva.de.", "type": "A", "ttl": 3600, "changetype": null, "comments": [],
+                 "records": [{"content": "192.0.2.1", "disabled": false}]}
+            ],
+            "serial": 2021110101, "edited_serial": 2021110101, "masters": [], "dnssec": false, "nsec3param": "",
+            "nsec3narrow": false, "soa_edit": "", "soa_edit_api": "", "api_rectify": false, "account": "",
+            "master_tsig_key_ids": [], "slave_tsig_key_ids": []
+        })).unwrap()
+    }
+
+    #[test]
+    fn should_show_unicode_zone_and_record_names() {
+        let json: serde_json::Value = serde_json::from_str(&render_json(&zone()).unwrap()).unwrap();
+
+        assert_eq!(json["unicode_name"], "bücher.de.");
+        assert_eq!(json["rrsets"][0]["unicode_name"], "www.bücher.de.");
+        assert_eq!(render_table(&zone()),
+                   "NAME                   UNICODE NAME    TTL   TYPE  CONTENT\n\
+                    www.xn--bcher-kva.de.  www.bücher.de.  3600  A     192.0.2.1\n")
+    }
+}
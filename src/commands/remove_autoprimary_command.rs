@@ -0,0 +1,68 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::pdns::version::Capability;
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+
+pub struct RemoveAutoprimaryCommand {
+    context: Arc<CommandContext>,
+}
+
+impl RemoveAutoprimaryCommand {
+    pub fn new(context: &Arc<CommandContext>) -> RemoveAutoprimaryCommand {
+        RemoveAutoprimaryCommand {
+            context: context.clone(),
+        }
+    }
+
+    async fn execute_remove_autoprimary(&self, ip: &String, nameserver: &String) -> Result<(), RestClientError> {
+        match self.context.server_client().remove_autoprimary(ip, nameserver).await {
+            Ok(()) => {
+                info!("Received remove autoprimary data event");
+
+                Ok(())
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for RemoveAutoprimaryCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::RemoveAutoprimary { ip, nameserver } = parameters {
+            info!("Executing command autoprimaries remove, ip {}, nameserver {}", &ip, &nameserver);
+
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    self.context.server_client().require_capability(&server, Capability::Autoprimaries)?;
+                    self.execute_remove_autoprimary(&ip, &nameserver).await
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
@@ -12,85 +12,89 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use chrono::Utc;
 use log::info;
+use pdns_api::pdns::dns_name::DnsName;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::pdns::soa::next_soa_rrset;
+use pdns_api::pdns::zone::Rrset;
+use pdns_api::rest_client::errors::{RestClientError, RestClientErrorKind};
+use pdns_api::rest_client::zone_resource_client::RemoveEntryRequest;
 use reqwest::StatusCode;
-use tokio::sync::oneshot::channel;
 
 use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
 use crate::commands::command_handler::CommandExecutor;
-use crate::pdns::server::{DaemonType, Server};
-use crate::pdns::zone::Zone;
-use crate::rest_client::errors::{RestClientError, RestClientErrorKind};
-use crate::rest_client::pdns_resource_client::PnsServerResponse;
-use crate::rest_client::server_resource_client::{QueryServerRequestEvent, ServerResourceClient};
-use crate::rest_client::zone_resource_client::{QueryZoneRequestEvent, ZoneResourceClient, RemoveEntryRequestEvent};
+use crate::commands::flush_cache_command::FlushCacheCommand;
+use crate::commands::reverse_pointer_records::ReversePointerRecords;
 
 pub struct RemoveEntryCommand {
-    base_uri: String,
-    api_key: String,
+    context: Arc<CommandContext>,
     zone_name: String,
 }
 
 impl RemoveEntryCommand {
-    pub fn new(base_uri: &String, api_key: &String, zone_name: &String) -> RemoveEntryCommand {
+    pub fn new(context: &Arc<CommandContext>, zone_name: &String) -> RemoveEntryCommand {
         RemoveEntryCommand {
-            base_uri: base_uri.clone(),
-            api_key: api_key.clone(),
+            context: context.clone(),
             zone_name: zone_name.clone(),
         }
     }
 
-    async fn execute_get_zone(&self, record_key: &String, record_type: &String) -> Result<(), RestClientError> {
-        let mut zone_resource_client = ZoneResourceClient::new(&self.base_uri, &self.api_key);
-        let (request_tx, request_rx) = channel::<QueryZoneRequestEvent>();
-        let (response_tx, response_rx) = channel::<PnsServerResponse<QueryZoneRequestEvent, Zone>>();
-
-        zone_resource_client.spawn_query_zone(request_rx, response_tx);
-
-        match request_tx.send(QueryZoneRequestEvent::new(&self.zone_name)) {
-            Ok(()) => match response_rx.await {
-                Ok(response_container) => match response_container.response() {
-                    Ok(zone) => {
-                        info!("Received zone data event: {}", zone);
-
-                        self.execute_remove_entry(record_key, record_type).await
+    async fn execute_get_zone(&self, record_key: &String, record_type: &String, with_ptr: bool) -> Result<Vec<String>, RestClientError> {
+        match self.context.zone_client().get_zone(&self.zone_name).await {
+            Ok(zone) => {
+                info!("Received zone data event: {}", zone);
+
+                let forward_name = DnsName::from_record_key(record_key, &DnsName::absolute(&self.zone_name));
+                let addresses: Vec<String> = zone.rrsets()
+                    .iter()
+                    .filter(|rrset| DnsName::absolute(&rrset.name()) == forward_name
+                        && rrset.type_id().to_string() == *record_type)
+                    .flat_map(|rrset| rrset.records())
+                    .map(|record| record.content())
+                    .collect();
+
+                let pointer_records = ReversePointerRecords::new(&self.context);
+                let reverse_names = match with_ptr && !addresses.is_empty() {
+                    true => Some(pointer_records.resolve_reverse_zones(&addresses).await?),
+                    false => None,
+                };
+
+                self.execute_remove_entry(record_key, record_type,
+                                          &next_soa_rrset(&zone, self.context.serial_policy(), &Utc::now())).await?;
+                match reverse_names {
+                    Some(reverse_names) => {
+                        pointer_records.remove_pointer_records(&forward_name.to_string(), &reverse_names).await?;
+
+                        Ok(reverse_names.into_values().flatten().collect())
                     }
-                    Err(error) => match error.kind() {
-                        RestClientErrorKind::PowerDnsServerError { status_code, server_error: _ } if status_code == StatusCode::NOT_FOUND => {
-                            info!("Existing zone not found");
+                    None => Ok(Vec::new()),
+                }
+            }
+            Err(error) => match error.kind() {
+                RestClientErrorKind::PowerDnsServerError { status_code, server_error: _ } if status_code == StatusCode::NOT_FOUND => {
+                    info!("Existing zone not found");
 
-                            Ok(())
-                        }
-                        _ => Err(error.clone())
-                    }
-                },
-                Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+                    Ok(Vec::new())
+                }
+                _ => Err(error)
             }
-            Err(_) => Err(RestClientError::on_unspecified_error()),
         }
     }
 
-    async fn execute_remove_entry(&self, record_key: &String, record_type: &String) -> Result<(), RestClientError> {
-        let mut zone_resource_client = ZoneResourceClient::new(&self.base_uri, &self.api_key);
-        let (request_tx, request_rx) = channel::<RemoveEntryRequestEvent>();
-        let (response_tx, response_rx) = channel::<PnsServerResponse<RemoveEntryRequestEvent, ()>>();
-
-        zone_resource_client.spawn_remove_entry(request_rx, response_tx);
-
-        match request_tx.send(RemoveEntryRequestEvent::new(&self.zone_name, record_key, record_type)) {
-            Ok(()) => match response_rx.await {
-                Ok(response_container) => match response_container.response() {
-                    Ok(()) => {
-                        info!("Received remove zone data event");
+    async fn execute_remove_entry(&self, record_key: &String, record_type: &String,
+                                  soa_rrset: &Option<Rrset>) -> Result<(), RestClientError> {
+        match self.context.zone_client().remove_entry(&RemoveEntryRequest::new(&self.zone_name, record_key, record_type, soa_rrset)).await {
+            Ok(()) => {
+                info!("Received remove zone data event");
 
-                        Ok(())
-                    }
-                    Err(error) => Err(error.clone()),
-                },
-                Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+                Ok(())
             }
-            Err(_) => Err(RestClientError::on_unspecified_error()),
+            Err(error) => Err(error),
         }
     }
 }
@@ -99,30 +103,27 @@ impl RemoveEntryCommand {
 impl CommandExecutor for RemoveEntryCommand {
     async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
         if let CommandParameters::RemoveEntry {
-            record_key, record_type,
+            record_key, record_type, with_ptr, flush,
         } = parameters {
-            info!("Executing command add-entry, zone {}", &self.zone_name);
-
-            let mut server_resource_client = ServerResourceClient::new(&self.base_uri, &self.api_key);
-            let (request_tx, request_rx) = channel::<QueryServerRequestEvent>();
-            let (response_tx, response_rx) = channel::<PnsServerResponse<QueryServerRequestEvent, Server>>();
+            info!("Executing command remove-entry, zone {}, key {}", &self.zone_name,
+                  DnsName::from_record_key(&record_key, &DnsName::absolute(&self.zone_name))
+                      .relative_to(&DnsName::absolute(&self.zone_name)));
 
-            server_resource_client.spawn_query(request_rx, response_tx);
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
 
-            match request_tx.send(QueryServerRequestEvent::new()) {
-                Ok(()) => match response_rx.await {
-                    Ok(response_container) => match response_container.response() {
-                        Ok(server) if server.daemon_type() == DaemonType::Authoritative => {
-                            info!("Received Server data event: {}", server);
+                    match self.execute_get_zone(&record_key, &record_type, with_ptr).await {
+                        Ok(mut names) if flush => {
+                            names.insert(0, DnsName::from_record_key(&record_key, &DnsName::absolute(&self.zone_name)).to_string());
 
-                            self.execute_get_zone(&record_key, &record_type).await
+                            FlushCacheCommand::new(&self.context).flush_names(&names).await
                         }
-                        Ok(_) => Err(RestClientError::on_unspecified_error()),
-                        Err(error) => Err(error.clone()),
-                    },
-                    Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+                        Ok(_) => Ok(()),
+                        Err(error) => Err(error),
+                    }
                 }
-                Err(_) => Err(RestClientError::on_unspecified_error()),
+                Err(error) => Err(error),
             }
         } else {
             Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
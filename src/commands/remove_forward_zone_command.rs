@@ -0,0 +1,102 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::dns_name::DnsName;
+use pdns_api::pdns::recursor::{RecursorZone, RecursorZoneKind};
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+
+pub struct RemoveForwardZoneCommand {
+    context: Arc<CommandContext>,
+    zone_name: String,
+}
+
+impl RemoveForwardZoneCommand {
+    pub fn new(context: &Arc<CommandContext>, zone_name: &String) -> RemoveForwardZoneCommand {
+        RemoveForwardZoneCommand {
+            context: context.clone(),
+            zone_name: zone_name.clone(),
+        }
+    }
+
+    /// Only forwarded zones are removed, the native zones of the recursor are loaded from its configuration
+    async fn execute_remove_forward_zone(&self) -> Result<(), RestClientError> {
+        let zone_name = DnsName::absolute(&self.zone_name).to_string();
+        let zone = self.context.recursor_client().get_zone(&zone_name).await?;
+
+        info!("Received recursor zone data event: {}", zone);
+        verify_forwarded_zone(&zone)?;
+
+        match self.context.recursor_client().remove_zone(&zone_name).await {
+            Ok(()) => {
+                info!("Received remove recursor zone data event");
+
+                Ok(())
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for RemoveForwardZoneCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::RemoveForwardZone {} = parameters {
+            info!("Executing command forward-zones remove, zone {}", &self.zone_name);
+
+            match self.context.server_client().get_server_of_type(DaemonType::Recursor).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    self.execute_remove_forward_zone().await
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
+
+fn verify_forwarded_zone(zone: &RecursorZone) -> Result<(), RestClientError> {
+    match zone.kind() {
+        RecursorZoneKind::Forwarded => Ok(()),
+        kind => Err(RestClientError::on_unspecified_error_message(
+            &format!("Zone {} is not a forwarded zone but {}", zone.name(), kind))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pdns_api::pdns::recursor::RecursorZone;
+
+    use crate::commands::remove_forward_zone_command::verify_forwarded_zone;
+
+    fn zone(kind: &str) -> RecursorZone {
+        serde_json::from_str(&format!(r#"{{"id": "example.com.", "name": "example.com.", "kind": "{}"}}"#, kind)).unwrap()
+    }
+
+    #[test]
+    fn should_only_remove_forwarded_zones() {
+        assert!(verify_forwarded_zone(&zone("Forwarded")).is_ok());
+        assert!(verify_forwarded_zone(&zone("Native")).is_err())
+    }
+}
@@ -11,85 +11,57 @@
 // WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
 // See the License for the specific language governing permissions and
 // limitations under the License.
-use log::info;
+use std::sync::Arc;
+
 use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::rest_client::errors::{RestClientError, RestClientErrorKind};
 use reqwest::StatusCode;
-use tokio::sync::oneshot::channel;
 
 use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
 use crate::commands::command_handler::CommandExecutor;
-use crate::pdns::server::{DaemonType, Server};
-use crate::pdns::zone::Zone;
-use crate::rest_client::errors::{RestClientError, RestClientErrorKind};
-use crate::rest_client::pdns_resource_client::PnsServerResponse;
-use crate::rest_client::server_resource_client::{QueryServerRequestEvent, ServerResourceClient};
-use crate::rest_client::zone_resource_client::{QueryZoneRequestEvent, RemoveZoneRequestEvent, ZoneResourceClient};
 
 pub struct RemoveZoneCommand {
-    base_uri: String,
-    api_key: String,
+    context: Arc<CommandContext>,
     zone_name: String,
 }
 
 impl RemoveZoneCommand {
-    pub fn new(base_uri: &String, api_key: &String, zone_name: &String) -> RemoveZoneCommand {
+    pub fn new(context: &Arc<CommandContext>, zone_name: &String) -> RemoveZoneCommand {
         RemoveZoneCommand {
-            base_uri: base_uri.clone(),
-            api_key: api_key.clone(),
+            context: context.clone(),
             zone_name: zone_name.clone(),
         }
     }
 
     async fn execute_get_zone(&self) -> Result<(), RestClientError> {
-        let mut zone_resource_client = ZoneResourceClient::new(&self.base_uri, &self.api_key);
-        let (request_tx, request_rx) = channel::<QueryZoneRequestEvent>();
-        let (response_tx, response_rx) = channel::<PnsServerResponse<QueryZoneRequestEvent, Zone>>();
+        match self.context.zone_client().get_zone(&self.zone_name).await {
+            Ok(zone) => {
+                info!("Received zone data event: {}", zone);
 
-        zone_resource_client.spawn_query_zone(request_rx, response_tx);
-
-        match request_tx.send(QueryZoneRequestEvent::new(&self.zone_name)) {
-            Ok(()) => match response_rx.await {
-                Ok(response_container) => match response_container.response() {
-                    Ok(zone) => {
-                        info!("Received zone data event: {}", zone);
-
-                        self.execute_remove_zone().await
-                    }
-                    Err(error) => match error.kind() {
-                        RestClientErrorKind::PowerDnsServerError { status_code, server_error: _ } if status_code == StatusCode::NOT_FOUND => {
-                            info!("Existing zone not found");
+                self.execute_remove_zone().await
+            }
+            Err(error) => match error.kind() {
+                RestClientErrorKind::PowerDnsServerError { status_code, server_error: _ } if status_code == StatusCode::NOT_FOUND => {
+                    info!("Existing zone not found");
 
-                            Ok(())
-                        }
-                        _ => Err(error.clone())
-                    }
-                },
-                Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+                    Ok(())
+                }
+                _ => Err(error)
             }
-            Err(_) => Err(RestClientError::on_unspecified_error()),
         }
     }
 
     async fn execute_remove_zone(&self) -> Result<(), RestClientError> {
-        let mut zone_resource_client = ZoneResourceClient::new(&self.base_uri, &self.api_key);
-        let (request_tx, request_rx) = channel::<RemoveZoneRequestEvent>();
-        let (response_tx, response_rx) = channel::<PnsServerResponse<RemoveZoneRequestEvent, ()>>();
-
-        zone_resource_client.spawn_remove_zone(request_rx, response_tx);
-
-        match request_tx.send(RemoveZoneRequestEvent::new(&self.zone_name)) {
-            Ok(()) => match response_rx.await {
-                Ok(response_container) => match response_container.response() {
-                    Ok(()) => {
-                        info!("Received remove zone data event");
+        match self.context.zone_client().remove_zone(&self.zone_name).await {
+            Ok(()) => {
+                info!("Received remove zone data event");
 
-                        Ok(())
-                    }
-                    Err(error) => Err(error.clone()),
-                },
-                Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+                Ok(())
             }
-            Err(_) => Err(RestClientError::on_unspecified_error()),
+            Err(error) => Err(error),
         }
     }
 }
@@ -100,26 +72,13 @@ impl CommandExecutor for RemoveZoneCommand {
         if let CommandParameters::RemoveZone {} = parameters {
             info!("Executing command remove-zone, zone {}", &self.zone_name);
 
-            let mut server_resource_client = ServerResourceClient::new(&self.base_uri, &self.api_key);
-            let (request_tx, request_rx) = channel::<QueryServerRequestEvent>();
-            let (response_tx, response_rx) = channel::<PnsServerResponse<QueryServerRequestEvent, Server>>();
-
-            server_resource_client.spawn_query(request_rx, response_tx);
-
-            match request_tx.send(QueryServerRequestEvent::new()) {
-                Ok(()) => match response_rx.await {
-                    Ok(response_container) => match response_container.response() {
-                        Ok(server) if server.daemon_type() == DaemonType::Authoritative => {
-                            info!("Received Server data event: {}", server);
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
 
-                            self.execute_get_zone().await
-                        }
-                        Ok(_) => Err(RestClientError::on_unspecified_error()),
-                        Err(error) => Err(error.clone()),
-                    },
-                    Err(error) => Err(RestClientError::on_tokio_runtime_error(error.to_string())),
+                    self.execute_get_zone().await
                 }
-                Err(_) => Err(RestClientError::on_unspecified_error()),
+                Err(error) => Err(error),
             }
         } else {
             Err(RestClientError::on_unspecified_error())
@@ -0,0 +1,384 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::{info, warn};
+use pdns_api::pdns::backup::{BACKUP_FORMAT_VERSION, BACKUP_MANIFEST_FILE, BackupEntry, BackupManifest, ZoneBackup};
+use pdns_api::pdns::dns_name::DnsName;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::backup_command::{BackupCommand, create_directory, write_file};
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+use crate::commands::output::{render_columns, write_output};
+
+/// Directory of the archive receiving the state of overwritten zones
+const REPLACED_DIRECTORY: &'static str = "replaced";
+
+pub struct RestoreCommand {
+    context: Arc<CommandContext>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RestoreAction {
+    Create,
+    Overwrite,
+    Skip,
+}
+
+impl RestoreCommand {
+    pub fn new(context: &Arc<CommandContext>) -> RestoreCommand {
+        RestoreCommand {
+            context: context.clone(),
+        }
+    }
+
+    async fn execute_list_zones(&self) -> Result<Vec<String>, RestClientError> {
+        match self.context.zone_client().list_zones().await {
+            Ok(zones) => {
+                info!("Received zone data event for number of zones: {}", zones.len());
+
+                Ok(zones.iter().map(|zone| zone.name()).collect())
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn execute_restore_zone(&self, backup: &ZoneBackup) -> Result<(), RestClientError> {
+        let zone = backup.zone();
+        let cryptokeys = backup.importable_cryptokeys();
+
+        self.context.zone_client().import_zone(&backup.new_zone()).await?;
+        if cryptokeys.len() < backup.cryptokeys().len() {
+            warn!("Zone {} has {} keys without private key, these are not restored",
+                zone.name(), backup.cryptokeys().len() - cryptokeys.len());
+        }
+        for cryptokey in cryptokeys.iter() {
+            self.context.zone_client().import_cryptokey(&zone.name(), cryptokey).await?;
+        }
+        for metadata in backup.restorable_metadata().iter() {
+            self.context.zone_client().replace_metadata(&zone.name(), metadata).await?;
+        }
+        self.context.zone_client().apply_zone_update(&zone.name(), &backup.zone_update()).await?;
+        if zone.dnssec() && !zone.presigned() && !zone.kind().is_secondary() {
+            self.context.zone_client().rectify_zone(&zone.name()).await?;
+        }
+        info!("Received restore zone data event for zone {}", zone.name());
+
+        Ok(())
+    }
+
+    /// Replace an existing zone. Its current state is saved to the archive before the zone is removed
+    /// and restored again if the zone of the backup can not be restored completely.
+    async fn execute_overwrite_zone(&self, directory: &Path, backup: &ZoneBackup) -> Result<(), RestClientError> {
+        let zone_name = backup.zone().name();
+        let previous = BackupCommand::new(&self.context).execute_backup_zone(&zone_name).await?;
+        let previous_file = directory.join(REPLACED_DIRECTORY).join(BackupEntry::new(&zone_name).file());
+
+        if let Some(parent) = previous_file.parent() {
+            create_directory(parent).await?;
+        }
+        write_file(&previous_file, &serde_json::to_string_pretty(&previous)
+            .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string()))?).await?;
+
+        self.context.zone_client().remove_zone(&zone_name).await?;
+        match self.execute_restore_zone(backup).await {
+            Ok(()) => Ok(()),
+            Err(error) => {
+                warn!("Restoring zone {} failed, reverting it to its previous state: {}", &zone_name, error);
+
+                // the zone is only partially restored, if it was created at all
+                if let Err(remove_error) = self.context.zone_client().remove_zone(&zone_name).await {
+                    info!("Partially restored zone {} not removed: {}", &zone_name, remove_error);
+                }
+                match self.execute_restore_zone(&previous).await {
+                    Ok(()) => Err(error),
+                    Err(revert_error) => Err(RestClientError::on_unspecified_error_message(
+                        &format!("{}, reverting zone {} failed as well: {}, its previous state is saved in {}",
+                                 error, &zone_name, revert_error, previous_file.display()))),
+                }
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for RestoreCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::Restore { archive, zones, overwrite, dry_run } = parameters {
+            info!("Executing command restore, archive {}", &archive);
+
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    let directory = Path::new(archive.as_str());
+                    let manifest = read_manifest(directory).await?;
+                    let mut backups: Vec<ZoneBackup> = Vec::new();
+
+                    info!("Restoring backup of {} taken at {}", manifest.server(), manifest.created_at());
+                    for entry in selected_entries(&manifest, &zones)?.iter() {
+                        backups.push(read_zone_backup(directory, entry).await?);
+                    }
+                    // catalogs first, so the member zones can refer to them
+                    backups.sort_by_key(|backup| !backup.zone().kind().is_catalog());
+                    let capabilities = backups.iter()
+                        .flat_map(|backup| backup.required_capabilities())
+                        .fold(Vec::new(), |mut capabilities, capability| {
+                            if !capabilities.contains(&capability) {
+                                capabilities.push(capability);
+                            }
+                            capabilities
+                        });
+
+                    for capability in capabilities.into_iter() {
+                        self.context.server_client().require_enabled_capability(&server, capability).await?;
+                    }
+
+                    let existing_zones = self.execute_list_zones().await?;
+                    let plan: Vec<(ZoneBackup, RestoreAction)> = backups.into_iter()
+                        .map(|backup| {
+                            let action = match (existing_zones.contains(&backup.zone().name()), overwrite) {
+                                (false, _) => RestoreAction::Create,
+                                (true, true) => RestoreAction::Overwrite,
+                                (true, false) => RestoreAction::Skip,
+                            };
+
+                            (backup, action)
+                        })
+                        .collect();
+
+                    if dry_run {
+                        return write_output(&render_table(&plan), &None).await;
+                    }
+                    for (backup, action) in plan.iter() {
+                        let zone_name = backup.zone().name();
+
+                        match action {
+                            RestoreAction::Create => self.execute_restore_zone(backup).await?,
+                            RestoreAction::Overwrite => self.execute_overwrite_zone(directory, backup).await?,
+                            RestoreAction::Skip => {}
+                        }
+                        write_output(&format!("{} {}\n", action, &zone_name), &None).await?;
+                    }
+
+                    Ok(())
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
+
+impl Display for RestoreAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestoreAction::Create => write!(f, "create"),
+            RestoreAction::Overwrite => write!(f, "overwrite"),
+            RestoreAction::Skip => write!(f, "skip (exists)"),
+        }
+    }
+}
+
+/// Entries of the selected zones, all entries without selection
+fn selected_entries(manifest: &BackupManifest, selection: &Vec<String>) -> Result<Vec<BackupEntry>, RestClientError> {
+    let selected: Vec<String> = selection.iter()
+        .map(|name| DnsName::absolute(name).to_string())
+        .collect();
+    let entries = manifest.zones();
+
+    match selected.iter().find(|name| !entries.iter().any(|entry| entry.name() == **name)) {
+        Some(name) => Err(RestClientError::on_unspecified_error_message(&format!("Zone {} is not part of the backup", name))),
+        None => Ok(entries.into_iter()
+            .filter(|entry| selected.is_empty() || selected.contains(&entry.name()))
+            .collect()),
+    }
+}
+
+async fn read_manifest(directory: &Path) -> Result<BackupManifest, RestClientError> {
+    let manifest: BackupManifest = serde_json::from_str(&read_file(&directory.join(BACKUP_MANIFEST_FILE)).await?)
+        .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string()))?;
+
+    match manifest.format_version() {
+        BACKUP_FORMAT_VERSION => Ok(manifest),
+        version => Err(RestClientError::on_unspecified_error_message(
+            &format!("Unsupported backup format version {}, expected {}", version, BACKUP_FORMAT_VERSION))),
+    }
+}
+
+async fn read_zone_backup(directory: &Path, entry: &BackupEntry) -> Result<ZoneBackup, RestClientError> {
+    if !entry.is_safe_file() {
+        return Err(RestClientError::on_unspecified_error_message(
+            &format!("Backup file {} of zone {} is outside of the archive", entry.file(), entry.name())));
+    }
+
+    serde_json::from_str(&read_file(&directory.join(entry.file())).await?)
+        .map_err(|error| RestClientError::on_unspecified_error_message(&format!("{}: {}", entry.file(), error)))
+}
+
+async fn read_file(path: &Path) -> Result<String, RestClientError> {
+    tokio::fs::read_to_string(path).await
+        .map_err(|error| RestClientError::on_unspecified_error_message(&format!("{}: {}", path.display(), error)))
+}
+
+fn render_table(plan: &Vec<(ZoneBackup, RestoreAction)>) -> String {
+    let mut rows: Vec<Vec<String>> = vec![vec!["ZONE".to_string(), "KIND".to_string(), "SERIAL".to_string(),
+                                               "KEYS".to_string(), "METADATA".to_string(), "ACTION".to_string()]];
+
+    rows.extend(plan.iter()
+        .map(|(backup, action)| vec![backup.zone().name(), backup.zone().kind().to_string(),
+                                     backup.zone().serial().to_string(),
+                                     backup.importable_cryptokeys().len().to_string(),
+                                     backup.restorable_metadata().len().to_string(), action.to_string()]));
+
+    render_columns(&rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use chrono::{TimeZone, Utc};
+    use pdns_api::pdns::backup::{BackupEntry, BackupManifest, ZoneBackup};
+    use pdns_api::pdns::soa::SerialPolicy;
+    use pdns_api::rest_client::client_configuration::ClientConfiguration;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    use crate::commands::command_context::CommandContext;
+    use crate::commands::restore_command::{RestoreCommand, selected_entries};
+
+    const ZONE_PATH: &'static str = "/api/v1/servers/localhost/zones/example.org.";
+    const ZONES_PATH: &'static str = "/api/v1/servers/localhost/zones";
+
+    fn zone(address: &str) -> String {
+        serde_json::json!({
+            "id": "example.org.", "name": "example.org.", "type": "Zone", "url": "/", "kind": "Native",
+            "rrsets": [
+                {"name": "example.org.", "type": "SOA", "ttl": 3600, "changetype": null, "comments": [],
+                 "records": [{"content": "example.org. root.example.org. 1 10800 3600 604800 3600", "disabled": false}]},
+                {"name": "www.example.org.", "type": "A", "ttl": 3600, "changetype": null, "comments": [],
+                 "records": [{"content": address, "disabled": false}]}
+            ],
+            "serial": 1, "edited_serial": 1, "masters": [], "dnssec": false, "nsec3param": "", "nsec3narrow": false,
+            "soa_edit": "", "soa_edit_api": "", "api_rectify": false, "account": "", "catalog": "",
+            "master_tsig_key_ids": [], "slave_tsig_key_ids": []
+        }).to_string()
+    }
+
+    /// Server answering each request by the handler, which gets the number of earlier requests with the
+    /// same method and path. The requests are recorded as '<method> <path> <body>'.
+    async fn serve(handler: fn(&str, &str, usize) -> (u16, String), requests: Arc<Mutex<Vec<String>>>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_uri = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let requests = requests.clone();
+
+                tokio::spawn(async move {
+                    let mut stream = BufReader::new(stream);
+                    let mut request_line = String::new();
+
+                    while stream.read_line(&mut request_line).await.unwrap_or(0) > 0 {
+                        let mut content_length = 0;
+                        let mut header = String::new();
+
+                        while stream.read_line(&mut header).await.unwrap() > 0 && !header.trim().is_empty() {
+                            if let Some(value) = header.to_lowercase().strip_prefix("content-length:") {
+                                content_length = value.trim().parse().unwrap();
+                            }
+                            header.clear();
+                        }
+
+                        let mut body = vec![0u8; content_length];
+                        stream.read_exact(&mut body).await.unwrap();
+
+                        let parts: Vec<String> = request_line.split_whitespace().map(|part| part.to_string()).collect();
+                        let (status, response) = {
+                            let mut requests = requests.lock().unwrap();
+                            let previous = requests.iter()
+                                .filter(|request| request.starts_with(&format!("{} {} ", parts[0], parts[1])))
+                                .count();
+
+                            requests.push(format!("{} {} {}", parts[0], parts[1], String::from_utf8_lossy(&body)));
+                            handler(&parts[0], &parts[1], previous)
+                        };
+
+                        stream.get_mut().write_all(format!("HTTP/1.1 {} Status\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{}",
+                                                           status, response.len(), response).as_bytes()).await.unwrap();
+                        request_line.clear();
+                    }
+                });
+            }
+        });
+
+        base_uri
+    }
+
+    #[test]
+    fn should_select_backup_entries() {
+        let manifest = BackupManifest::new(&Utc.ymd(2021, 11, 1).and_hms(12, 0, 0), &"http://localhost:8081".to_string(),
+                                           &"4.5.2".to_string(), &vec![BackupEntry::new(&"a.example.".to_string()),
+                                                                       BackupEntry::new(&"b.example.".to_string())]);
+
+        assert_eq!(selected_entries(&manifest, &Vec::new()).unwrap().len(), 2);
+        assert_eq!(selected_entries(&manifest, &vec!["b.example".to_string()]).unwrap(),
+                   vec![BackupEntry::new(&"b.example.".to_string())]);
+        assert!(selected_entries(&manifest, &vec!["c.example".to_string()]).is_err());
+    }
+
+    #[tokio::test]
+    async fn should_revert_overwritten_zone_on_failure() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let base_uri = serve(|method, path, previous| match (method, path) {
+            ("GET", ZONE_PATH) => (200, zone("198.51.100.1")),
+            ("GET", _) => (200, "[]".to_string()),
+            ("POST", ZONES_PATH) if previous == 0 => (422, "{\"error\": \"Invalid record\"}".to_string()),
+            ("POST", ZONES_PATH) => (201, zone("198.51.100.1")),
+            ("DELETE", ZONE_PATH) | ("PUT", ZONE_PATH) => (204, String::new()),
+            _ => (404, "{\"error\": \"Not found\"}".to_string()),
+        }, requests.clone()).await;
+        let context = Arc::new(CommandContext::new(&base_uri, &"secret".to_string(), &ClientConfiguration::default(),
+                                                   &SerialPolicy::None));
+        let directory = std::env::temp_dir().join(format!("pdns-cli-restore-{}", std::process::id()));
+        let backup: ZoneBackup = serde_json::from_value(serde_json::json!({
+            "zone": serde_json::from_str::<serde_json::Value>(&zone("192.0.2.1")).unwrap(),
+            "metadata": [],
+            "cryptokeys": []
+        })).unwrap();
+
+        assert!(RestoreCommand::new(&context).execute_overwrite_zone(&directory, &backup).await.is_err());
+
+        let requests = requests.lock().unwrap();
+        let posts: Vec<&String> = requests.iter().filter(|request| request.starts_with("POST ")).collect();
+        let delete = requests.iter().position(|request| request.starts_with("DELETE ")).unwrap();
+
+        assert!(requests.iter().position(|request| request.starts_with(&format!("GET {} ", ZONE_PATH))).unwrap() < delete);
+        assert!(delete < requests.iter().position(|request| request.starts_with("POST ")).unwrap());
+        assert_eq!(posts.len(), 2);
+        assert!(posts[0].contains("192.0.2.1"));
+        assert!(posts[1].contains("198.51.100.1"));
+        assert!(std::fs::read_to_string(directory.join("replaced/zones/example.org.json")).unwrap().contains("198.51.100.1"));
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+}
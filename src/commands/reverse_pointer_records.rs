@@ -0,0 +1,217 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::Utc;
+use log::{info, warn};
+use pdns_api::pdns::dns_name::DnsName;
+use pdns_api::pdns::reverse::find_reverse_pointer;
+use pdns_api::pdns::soa::next_soa_rrset;
+use pdns_api::pdns::zone::{Changetype, ListZone, Record, Rrset, RrsetType, Zone};
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::commands::command_context::CommandContext;
+
+/// Keeps the PTR records in the reverse zones in sync with the A and AAAA records of a forward zone
+pub struct ReversePointerRecords {
+    context: Arc<CommandContext>,
+}
+
+/// PTR record names by the reverse zone holding them
+pub type ReverseNames = BTreeMap<String, Vec<String>>;
+
+impl ReversePointerRecords {
+    pub fn new(context: &Arc<CommandContext>) -> ReversePointerRecords {
+        ReversePointerRecords {
+            context: context.clone(),
+        }
+    }
+
+    /// Reverse zones of the given addresses, fails if one of the addresses has none. Resolved
+    /// before the forward zone is changed, so a missing reverse zone leaves all zones untouched.
+    pub async fn resolve_reverse_zones(&self, addresses: &Vec<String>) -> Result<ReverseNames, RestClientError> {
+        let zone_names: Vec<String> = self.execute_list_zones().await?
+            .iter()
+            .map(|zone| zone.name())
+            .collect();
+
+        reverse_zones_of(addresses, &zone_names)
+    }
+
+    /// Create or replace the PTR records pointing to the forward name
+    pub async fn add_pointer_records(&self, forward_name: &String, reverse_names: &ReverseNames,
+                                     time_to_live: u32) -> Result<(), RestClientError> {
+        for (zone_name, names) in reverse_names.iter() {
+            let zone = self.execute_get_zone(zone_name).await?;
+            let mut rrsets: Vec<Rrset> = Vec::new();
+
+            for name in names.iter() {
+                info!("Adding PTR record {} -> {} to zone {}", name, forward_name, zone_name);
+
+                rrsets.push(Rrset::new(name, RrsetType::Ptr,
+                                       &Some(Changetype::Replace),
+                                       &Some(time_to_live),
+                                       &vec![Record::new(forward_name, false)],
+                                       &Vec::new()));
+            }
+
+            if let Some(soa_rrset) = next_soa_rrset(&zone, self.context.serial_policy(), &Utc::now()) {
+                rrsets.push(soa_rrset);
+            }
+
+            self.execute_patch_rrsets(zone_name, &rrsets).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Remove the PTR records as far as they point to the forward name
+    pub async fn remove_pointer_records(&self, forward_name: &String,
+                                        reverse_names: &ReverseNames) -> Result<(), RestClientError> {
+        for (zone_name, names) in reverse_names.iter() {
+            let zone = self.execute_get_zone(zone_name).await?;
+            let mut rrsets: Vec<Rrset> = Vec::new();
+
+            for rrset in zone.rrsets().iter()
+                .filter(|rrset| rrset.type_id() == RrsetType::Ptr
+                    && names.iter().any(|name| DnsName::absolute(name) == DnsName::absolute(&rrset.name()))) {
+                let remaining: Vec<Record> = rrset.records()
+                    .into_iter()
+                    .filter(|record| DnsName::absolute(&record.content()) != DnsName::absolute(forward_name))
+                    .collect();
+
+                if remaining.len() == rrset.records().len() {
+                    continue;
+                }
+
+                info!("Removing PTR record {} -> {} from zone {}", rrset.name(), forward_name, zone_name);
+
+                if remaining.is_empty() {
+                    rrsets.push(Rrset::new(&rrset.name(), RrsetType::Ptr,
+                                           &Some(Changetype::Delete),
+                                           &None,
+                                           &Vec::new(),
+                                           &Vec::new()));
+                } else {
+                    rrsets.push(Rrset::new(&rrset.name(), RrsetType::Ptr,
+                                           &Some(Changetype::Replace),
+                                           &rrset.ttl(),
+                                           &remaining,
+                                           &Vec::new()));
+                }
+            }
+
+            if rrsets.is_empty() {
+                continue;
+            }
+
+            if let Some(soa_rrset) = next_soa_rrset(&zone, self.context.serial_policy(), &Utc::now()) {
+                rrsets.push(soa_rrset);
+            }
+
+            self.execute_patch_rrsets(zone_name, &rrsets).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn execute_list_zones(&self) -> Result<Vec<ListZone>, RestClientError> {
+        match self.context.zone_client().list_zones().await {
+            Ok(zones) => {
+                info!("Received zone data event for number of zones: {}", zones.len());
+
+                Ok(zones.clone())
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn execute_get_zone(&self, zone_name: &String) -> Result<Zone, RestClientError> {
+        match self.context.zone_client().get_zone(zone_name).await {
+            Ok(zone) => {
+                info!("Received reverse zone data event: {}", zone);
+
+                Ok(zone.clone())
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    async fn execute_patch_rrsets(&self, zone_name: &String, rrsets: &Vec<Rrset>) -> Result<(), RestClientError> {
+        match self.context.zone_client().patch_rrsets(zone_name, rrsets).await {
+            Ok(()) => {
+                info!("Received patch reverse zone data event");
+
+                Ok(())
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+fn reverse_zones_of(addresses: &Vec<String>, zone_names: &Vec<String>) -> Result<ReverseNames, RestClientError> {
+    let mut reverse_names: ReverseNames = BTreeMap::new();
+
+    for address in addresses.iter() {
+        let ip_address = match IpAddr::from_str(address.as_str()) {
+            Ok(ip_address) => ip_address,
+            Err(_) => return Err(RestClientError::on_unspecified_error_message(&format!("Not an IP address: {}", address))),
+        };
+
+        match find_reverse_pointer(&ip_address, zone_names) {
+            Some((zone_name, reverse_name)) => reverse_names.entry(zone_name).or_default().push(reverse_name),
+            None => {
+                warn!("No reverse zone found for address {}", address);
+
+                return Err(RestClientError::on_unspecified_error_message(&format!("No reverse zone found for address {}", address)));
+            }
+        }
+    }
+
+    Ok(reverse_names)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::commands::reverse_pointer_records::reverse_zones_of;
+
+    #[test]
+    fn should_group_pointer_names_by_reverse_zone() {
+        let zone_names = vec!["example.org.".to_string(), "2.0.192.in-addr.arpa.".to_string()];
+        let reverse_names = reverse_zones_of(&vec!["192.0.2.1".to_string(), "192.0.2.2".to_string()], &zone_names).unwrap();
+
+        assert_eq!(reverse_names.get("2.0.192.in-addr.arpa."),
+                   Some(&vec!["1.2.0.192.in-addr.arpa.".to_string(), "2.2.0.192.in-addr.arpa.".to_string()]));
+    }
+
+    #[test]
+    fn should_place_pointer_names_in_classless_reverse_zone() {
+        let zone_names = vec!["26.16.172.in-addr.arpa.".to_string(), "128-26.26.16.172.in-addr.arpa.".to_string()];
+        let reverse_names = reverse_zones_of(&vec!["172.16.26.130".to_string(), "172.16.26.4".to_string()], &zone_names).unwrap();
+
+        assert_eq!(reverse_names.get("128-26.26.16.172.in-addr.arpa."),
+                   Some(&vec!["130.128-26.26.16.172.in-addr.arpa.".to_string()]));
+        assert_eq!(reverse_names.get("26.16.172.in-addr.arpa."), Some(&vec!["4.26.16.172.in-addr.arpa.".to_string()]));
+    }
+
+    #[test]
+    fn should_fail_address_without_reverse_zone() {
+        let zone_names = vec!["example.org.".to_string(), "2.0.192.in-addr.arpa.".to_string()];
+
+        assert!(reverse_zones_of(&vec!["192.0.2.1".to_string(), "198.51.100.1".to_string()], &zone_names).is_err());
+    }
+}
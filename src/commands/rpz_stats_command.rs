@@ -0,0 +1,91 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use log::info;
+use pdns_api::pdns::recursor::RpzStatistics;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+use crate::commands::output::{render_columns, write_output};
+
+pub struct RpzStatsCommand {
+    context: Arc<CommandContext>,
+}
+
+impl RpzStatsCommand {
+    pub fn new(context: &Arc<CommandContext>) -> RpzStatsCommand {
+        RpzStatsCommand {
+            context: context.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for RpzStatsCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::RpzStats { format, output_file } = parameters {
+            info!("Executing command rpz-stats");
+
+            match self.context.server_client().get_server_of_type(DaemonType::Recursor).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    let statistics = self.context.recursor_client().get_rpz_statistics().await?;
+
+                    info!("Received RPZ statistics data event for number of zones: {}", statistics.len());
+
+                    let output = match format.as_str() {
+                        "json" => serde_json::to_string_pretty(&statistics)
+                            .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string()))?,
+                        _ => render_table(&statistics),
+                    };
+
+                    write_output(&output, &output_file).await
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
+
+fn render_table(statistics: &BTreeMap<String, RpzStatistics>) -> String {
+    let mut rows: Vec<Vec<String>> = vec![["ZONE", "SERIAL", "RECORDS", "LAST UPDATE", "TRANSFERS OK", "FAILED", "FULL"]
+        .iter()
+        .map(|header| header.to_string())
+        .collect()];
+
+    rows.extend(statistics.iter()
+        .map(|(zone, rpz)| vec![
+            zone.clone(),
+            rpz.serial().to_string(),
+            rpz.records().to_string(),
+            Utc.timestamp_opt(rpz.last_update() as i64, 0).single()
+                .map(|last_update| last_update.to_rfc3339())
+                .unwrap_or(rpz.last_update().to_string()),
+            rpz.transfers_success().to_string(),
+            rpz.transfers_failed().to_string(),
+            rpz.transfers_full().to_string(),
+        ]));
+
+    render_columns(&rows)
+}
@@ -0,0 +1,90 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::config::ConfigSetting;
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+use crate::commands::output::{render_columns, write_output};
+
+pub struct ServerConfigCommand {
+    context: Arc<CommandContext>,
+}
+
+impl ServerConfigCommand {
+    pub fn new(context: &Arc<CommandContext>) -> ServerConfigCommand {
+        ServerConfigCommand {
+            context: context.clone(),
+        }
+    }
+
+    async fn execute_get_config(&self, setting: &Option<String>) -> Result<Vec<ConfigSetting>, RestClientError> {
+        match setting {
+            Some(name) => match self.context.server_client().get_config_setting(name).await {
+                Ok(config_setting) => {
+                    info!("Received config setting data event: {}", config_setting);
+
+                    Ok(vec![config_setting])
+                }
+                Err(error) => Err(error),
+            },
+            None => match self.context.server_client().get_config().await {
+                Ok(config_settings) => {
+                    info!("Received config data event for number of settings: {}", config_settings.len());
+
+                    Ok(config_settings)
+                }
+                Err(error) => Err(error),
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for ServerConfigCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::ServerConfig { setting, format, output_file } = parameters {
+            info!("Executing command server-config");
+
+            match self.execute_get_config(&setting).await {
+                Ok(config_settings) => {
+                    let output = match format.as_str() {
+                        "json" => serde_json::to_string_pretty(&config_settings)
+                            .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string()))?,
+                        _ => render_table(&config_settings),
+                    };
+
+                    write_output(&output, &output_file).await
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
+
+fn render_table(config_settings: &Vec<ConfigSetting>) -> String {
+    let mut rows: Vec<Vec<String>> = vec![vec!["NAME".to_string(), "VALUE".to_string()]];
+
+    rows.extend(config_settings.iter()
+        .map(|config_setting| vec![config_setting.name(), config_setting.value().to_string()]));
+
+    render_columns(&rows)
+}
@@ -0,0 +1,193 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::statistics::{SimpleStatisticItem, Statistic};
+use pdns_api::rest_client::errors::RestClientError;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+use crate::commands::output::{render_columns, write_output};
+
+/// Prefix of the metric names in the Prometheus text exposition
+const METRIC_PREFIX: &'static str = "pdns_";
+
+pub struct StatsCommand {
+    context: Arc<CommandContext>,
+}
+
+impl StatsCommand {
+    pub fn new(context: &Arc<CommandContext>) -> StatsCommand {
+        StatsCommand {
+            context: context.clone(),
+        }
+    }
+
+    async fn execute_get_statistics(&self, statistic: &Option<String>, include_rings: bool,
+                                    format: &String) -> Result<String, RestClientError> {
+        match self.context.server_client().get_statistics(statistic, include_rings).await {
+            Ok(statistics) => {
+                info!("Received statistics data event for number of statistics: {}", statistics.len());
+
+                match format.as_str() {
+                    "json" => serde_json::to_string_pretty(&statistics)
+                        .map_err(|error| RestClientError::on_unspecified_error_message(&error.to_string())),
+                    "prometheus" => Ok(render_prometheus(&statistics)),
+                    _ => Ok(render_table(&statistics)),
+                }
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for StatsCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::Stats { statistic, include_rings, format, output_file } = parameters {
+            info!("Executing command stats");
+
+            match self.context.server_client().get_server().await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    match self.execute_get_statistics(&statistic, include_rings, &format).await {
+                        Ok(output) => write_output(&output, &output_file).await,
+                        Err(error) => Err(error),
+                    }
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
+
+/// Two column table, map and ring entries are listed as '<statistic>[<key>]'
+fn render_table(statistics: &Vec<Statistic>) -> String {
+    let mut rows: Vec<Vec<String>> = vec![vec!["NAME".to_string(), "VALUE".to_string()]];
+
+    for statistic in statistics.iter() {
+        match statistic {
+            Statistic::StatisticItem(item) => rows.push(vec![item.name(), item.value()]),
+            Statistic::MapStatisticItem(item) => rows.extend(keyed_rows(&item.name(), &item.value())),
+            Statistic::RingStatisticItem(item) => rows.extend(keyed_rows(&item.name(), &item.value())),
+        }
+    }
+
+    render_columns(&rows)
+}
+
+fn keyed_rows(name: &String, entries: &Vec<SimpleStatisticItem>) -> Vec<Vec<String>> {
+    entries.iter()
+        .map(|entry| vec![format!("{}[{}]", name, entry.name()), entry.value()])
+        .collect()
+}
+
+/// Prometheus text exposition. The API does not tell counters from gauges, all metrics are
+/// untyped. Map entries carry their key as label, ring buffers get the suffix '_ring' as some
+/// of them share the name of a counter. Values which are not numbers are left out.
+fn render_prometheus(statistics: &Vec<Statistic>) -> String {
+    let mut exposition = String::new();
+
+    for statistic in statistics.iter() {
+        let (metric, samples) = match statistic {
+            Statistic::StatisticItem(item) => (metric_name(&item.name()), vec![(None, item.value())]),
+            Statistic::MapStatisticItem(item) => (metric_name(&item.name()), labeled_samples(&item.value())),
+            Statistic::RingStatisticItem(item) => (format!("{}_ring", metric_name(&item.name())),
+                                                   labeled_samples(&item.value())),
+        };
+        let samples: Vec<(Option<String>, String)> = samples.into_iter()
+            .filter(|(_, value)| value.parse::<f64>().is_ok())
+            .collect();
+
+        if samples.is_empty() {
+            continue;
+        }
+
+        exposition.push_str(format!("# TYPE {} untyped\n", &metric).as_str());
+
+        for (key, value) in samples.iter() {
+            match key {
+                Some(key) => exposition.push_str(format!("{}{{key=\"{}\"}} {}\n", &metric, escape_label(key), value).as_str()),
+                None => exposition.push_str(format!("{} {}\n", &metric, value).as_str()),
+            }
+        }
+    }
+
+    exposition
+}
+
+fn labeled_samples(entries: &Vec<SimpleStatisticItem>) -> Vec<(Option<String>, String)> {
+    entries.iter()
+        .map(|entry| (Some(entry.name()), entry.value()))
+        .collect()
+}
+
+fn metric_name(name: &String) -> String {
+    let name: String = name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+
+    format!("{}{}", METRIC_PREFIX, name)
+}
+
+fn escape_label(value: &String) -> String {
+    value.replace("\\", "\\\\")
+        .replace("\"", "\\\"")
+        .replace("\n", "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use pdns_api::pdns::statistics::{MapStatisticItem, RingStatisticItem, SimpleStatisticItem, Statistic, StatisticItem};
+
+    use crate::commands::stats_command::{render_prometheus, render_table};
+
+    fn statistics() -> Vec<Statistic> {
+        vec![
+            Statistic::StatisticItem(StatisticItem::new(&"udp-queries".to_string(), &"4711".to_string())),
+            Statistic::StatisticItem(StatisticItem::new(&"version".to_string(), &"4.5.0".to_string())),
+            Statistic::MapStatisticItem(MapStatisticItem::new(&"response-by-qtype".to_string(),
+                                                              &vec![SimpleStatisticItem::new(&"A".to_string(), &"42".to_string())])),
+            Statistic::RingStatisticItem(RingStatisticItem::new(&"queries".to_string(), 10000,
+                                                                &vec![SimpleStatisticItem::new(&"exa\"mple.com/A".to_string(), &"7".to_string())])),
+        ]
+    }
+
+    #[test]
+    fn should_render_prometheus_exposition() {
+        assert_eq!(render_prometheus(&statistics()),
+                   "# TYPE pdns_udp_queries untyped\n\
+                    pdns_udp_queries 4711\n\
+                    # TYPE pdns_response_by_qtype untyped\n\
+                    pdns_response_by_qtype{key=\"A\"} 42\n\
+                    # TYPE pdns_queries_ring untyped\n\
+                    pdns_queries_ring{key=\"exa\\\"mple.com/A\"} 7\n")
+    }
+
+    #[test]
+    fn should_render_table() {
+        assert_eq!(render_table(&statistics()),
+                   "NAME                     VALUE\n\
+                    udp-queries              4711\n\
+                    version                  4.5.0\n\
+                    response-by-qtype[A]     42\n\
+                    queries[exa\"mple.com/A]  7\n")
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 Cumulus Cloud Software und Consulting GmbH & Co KG
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use log::info;
+use pdns_api::pdns::server::DaemonType;
+use pdns_api::rest_client::errors::RestClientError;
+use pdns_api::rest_client::zone_resource_client::UpdateZoneRequest;
+
+use crate::app_config::cmd_line_parser::CommandParameters;
+use crate::commands::command_context::CommandContext;
+use crate::commands::command_handler::CommandExecutor;
+
+pub struct UpdateZoneCommand {
+    context: Arc<CommandContext>,
+    zone_name: String,
+}
+
+impl UpdateZoneCommand {
+    pub fn new(context: &Arc<CommandContext>, zone_name: &String) -> UpdateZoneCommand {
+        UpdateZoneCommand {
+            context: context.clone(),
+            zone_name: zone_name.clone(),
+        }
+    }
+
+    async fn execute_get_zone(&self, parameters: &CommandParameters) -> Result<(), RestClientError> {
+        match self.context.zone_client().get_zone(&self.zone_name).await {
+            Ok(zone) => {
+                info!("Received zone data event: {}", zone);
+
+                self.execute_update_zone(parameters).await
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    fn update_zone_request(&self, parameters: &CommandParameters) -> Result<UpdateZoneRequest, RestClientError> {
+        match parameters {
+            CommandParameters::UpdateZone {
+                kind, masters, account, soa_edit, soa_edit_api, api_rectify, dnssec, nsec3param, catalog,
+            } => Ok(UpdateZoneRequest::new(&self.zone_name)
+                .with_kind(kind)
+                .with_masters(masters)
+                .with_account(account)
+                .with_soa_edit(soa_edit, soa_edit_api)
+                .with_api_rectify(*api_rectify)
+                .with_dnssec(*dnssec, nsec3param)
+                .with_catalog(catalog)),
+            _ => Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string())),
+        }
+    }
+
+    async fn execute_update_zone(&self, parameters: &CommandParameters) -> Result<(), RestClientError> {
+        let request_event = self.update_zone_request(parameters)?;
+
+        match self.context.zone_client().update_zone(&request_event).await {
+            Ok(()) => {
+                info!("Received update zone data event");
+
+                Ok(())
+            }
+            Err(error) => Err(error),
+        }
+    }
+}
+
+#[async_trait]
+impl CommandExecutor for UpdateZoneCommand {
+    async fn execute_command(&self, parameters: CommandParameters) -> Result<(), RestClientError> {
+        if let CommandParameters::UpdateZone { .. } = parameters {
+            info!("Executing command update-zone, zone {}", &self.zone_name);
+
+            match self.context.server_client().get_server_of_type(DaemonType::Authoritative).await {
+                Ok(server) => {
+                    info!("Received Server data event: {}", server);
+
+                    self.update_zone_request(&parameters)?.required_capabilities()
+                        .iter()
+                        .try_for_each(|capability| self.context.server_client().require_capability(&server, *capability))?;
+                    self.execute_get_zone(&parameters).await
+                }
+                Err(error) => Err(error),
+            }
+        } else {
+            Err(RestClientError::on_unspecified_error_message(&"command parameter mismatch".to_string()))
+        }
+    }
+}
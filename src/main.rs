@@ -16,13 +16,12 @@ use log4rs::append::console::{ConsoleAppender, Target};
 use log4rs::Config;
 use log4rs::config::{Appender, Root};
 use log4rs::filter::threshold::ThresholdFilter;
+use pdns_api::pdns::dns_name::display_name;
 
 use crate::app_config::cmd_line_parser::ApplicationConfiguration;
 use crate::commands::command_handler::CommandHandler;
 
 mod app_config;
-mod rest_client;
-mod pdns;
 mod commands;
 
 #[tokio::main]
@@ -32,9 +31,16 @@ async fn main() {
             setup_logger(&app_config);
 
             info!("Using base URI {}", app_config.base_uri().clone());
+            info!("Using client configuration {}", app_config.client_configuration());
+
+            if !app_config.zone_name().is_empty() {
+                info!("Using zone {}", display_name(&app_config.zone_name()));
+            }
 
             let command_handler = CommandHandler::new(&app_config.base_uri(),
-                                                      &app_config.api_key(), &app_config.zone_name());
+                                                      &app_config.api_key(), &app_config.client_configuration(),
+                                                      &app_config.zone_name(),
+                                                      &app_config.serial_policy());
             let result = command_handler.execute_command(app_config.command());
 
             match result.await {